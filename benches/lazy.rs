@@ -0,0 +1,65 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lazy adapters against eager `_vec` variants of [fun](pa::basic_fn::fun).
+//! Only first few elements are consumed, which is the common case of pipelines.
+
+#[macro_use]
+extern crate criterion;
+extern crate pa;
+
+use criterion::{black_box, Criterion};
+use pa::basic_fn::fun::*;
+
+const N: u64 = 100_000;
+
+fn tail_init(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tail_init");
+    group.bench_function("lazy", |b| b.iter(|| {
+        let it = init(tail(0..black_box(N)).unwrap()).unwrap();
+        sum(take(10, it))
+    }));
+    group.bench_function("eager", |b| b.iter(|| {
+        let v = init_vec(tail_vec(0..black_box(N)).unwrap().into_iter()).unwrap();
+        sum(take_vec(10, v.into_iter()).into_iter())
+    }));
+    group.finish();
+}
+
+fn skip_take(c: &mut Criterion) {
+    let mut group = c.benchmark_group("skip_take");
+    group.bench_function("lazy", |b| b.iter(|| sum(take(10, skip(10, 0..black_box(N))))));
+    group.bench_function("eager", |b| b.iter(|| sum(take_vec(10, skip_vec(10, 0..black_box(N)).into_iter()).into_iter())));
+    group.finish();
+}
+
+fn reverse_concat(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reverse_concat");
+    group.bench_function("lazy", |b| b.iter(|| {
+        head(reverse(concat(0..black_box(N), 0..black_box(N))))
+    }));
+    group.bench_function("eager", |b| b.iter(|| {
+        head(reverse_vec(concat_vec(0..black_box(N), 0..black_box(N)).into_iter()).into_iter())
+    }));
+    group.finish();
+}
+
+fn zip_with_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zip");
+    group.bench_function("lazy", |b| b.iter(|| {
+        sum(take(10, zip_with(|(x, y)| x * y, 0..black_box(N), 0..black_box(N))))
+    }));
+    group.bench_function("eager", |b| b.iter(|| {
+        let xs: Vec<u64> = (0..black_box(N)).collect();
+        let ys: Vec<u64> = (0..black_box(N)).collect();
+        sum(take(10, zip_with(|(x, y)| x * y, xs.into_iter(), ys.into_iter())))
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, tail_init, skip_take, reverse_concat, zip_with_sum);
+criterion_main!(benches);
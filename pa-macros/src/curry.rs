@@ -0,0 +1,252 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Expansion of `#[curry]`.
+//!
+//! `fn f<..>(a: A, b: B, c: C) -> R` is expanded into
+//!
+//! ```text
+//! fn f<'__pa, ..>(a: A) -> impl Fn(B) -> Box<dyn Fn(C) -> R + '__pa> + '__pa
+//!     where A: Clone + '__pa, B: Clone + '__pa
+//! ```
+//!
+//! Nested `impl Trait` is not allowed in the return type of `Fn`, so every stage except the
+//! outermost one is boxed. Captured arguments are cloned on each call, as the stages are `Fn`.
+
+use proc_macro2::{Span, TokenStream};
+use syn::parse::{Error, Parse, ParseStream, Result};
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse2, FnArg, GenericParam, Ident, ItemFn, Lifetime, LifetimeParam, Pat, Path, ReturnType,
+          Type, TypeImplTrait, TypeParam, TypeReference, WherePredicate};
+
+use krate;
+
+const LIFETIME: &str = "'__pa";
+
+/// Arguments of `#[curry(name, crate = path)]`, both are optional
+struct Args {
+    companion: Option<Ident>,
+    krate: Path,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Args { companion: None, krate: krate::default() };
+        if !input.is_empty() && !krate::peek(input) {
+            args.companion = Some(input.parse()?);
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        if !input.is_empty() {
+            args.krate = krate::parse(input)?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let Args { companion, krate } = parse2(attr)?;
+    let func: ItemFn = parse2(item)?;
+    check(&func)?;
+
+    let arity = func.sig.inputs.len();
+    if arity < 2 {
+        return match companion {
+            Some(name) => Err(Error::new(name.span(), "#[curry] needs a function with at least two arguments")),
+            None => Ok(quote!(#func)),
+        };
+    }
+
+    let curried = curried(&func, companion.as_ref(), &krate)?;
+    Ok(match companion {
+        Some(_) => quote!(#func #curried),
+        None => curried,
+    })
+}
+
+fn check(func: &ItemFn) -> Result<()> {
+    let sig = &func.sig;
+    if let Some(c) = sig.constness {
+        return Err(Error::new(c.span, "#[curry] cannot be applied to `const fn`"));
+    }
+    if let Some(a) = sig.asyncness {
+        return Err(Error::new(a.span, "#[curry] cannot be applied to `async fn`"));
+    }
+    if let Some(u) = sig.unsafety {
+        return Err(Error::new(u.span, "#[curry] cannot be applied to `unsafe fn`"));
+    }
+    if let Some(ref v) = sig.variadic {
+        return Err(Error::new(v.span(), "#[curry] cannot be applied to variadic functions"));
+    }
+    if let Some(FnArg::Receiver(r)) = sig.inputs.first() {
+        return Err(Error::new(r.span(), "#[curry] cannot be applied to methods"));
+    }
+    Ok(())
+}
+
+/// Turn `impl Trait` arguments into generic parameters and elided lifetimes into `'__pa`.
+struct Normalize {
+    lifetime: Lifetime,
+    params: Vec<TypeParam>,
+}
+
+impl VisitMut for Normalize {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::ImplTrait(TypeImplTrait { ref bounds, .. }) = *ty {
+            let ident = Ident::new(&format!("__PaImpl{}", self.params.len()), ty.span());
+            self.params.push(parse_quote!(#ident: #bounds));
+            *ty = parse_quote!(#ident);
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_type_reference_mut(&mut self, r: &mut TypeReference) {
+        if r.lifetime.is_none() {
+            r.lifetime = Some(self.lifetime.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, r);
+    }
+
+    fn visit_lifetime_mut(&mut self, l: &mut Lifetime) {
+        if l.ident == "_" {
+            *l = self.lifetime.clone();
+        }
+    }
+}
+
+fn curried(func: &ItemFn, companion: Option<&Ident>, krate: &Path) -> Result<TokenStream> {
+    let lifetime = Lifetime::new(LIFETIME, Span::call_site());
+    let mut normalize = Normalize { lifetime: lifetime.clone(), params: Vec::new() };
+
+    let mut pats: Vec<Pat> = Vec::new();
+    let mut tys: Vec<Type> = Vec::new();
+    for input in &func.sig.inputs {
+        if let FnArg::Typed(ref arg) = *input {
+            let mut ty = (*arg.ty).clone();
+            normalize.visit_type_mut(&mut ty);
+            pats.push((*arg.pat).clone());
+            tys.push(ty);
+        }
+    }
+    let args: Vec<Ident> = (0..tys.len())
+        .map(|i| Ident::new(&format!("__pa_arg{}", i), Span::mixed_site()))
+        .collect();
+
+    // `impl Trait` in return position is boxed as `dyn Trait`.
+    let (ret, boxed) = match func.sig.output {
+        ReturnType::Default => (parse_quote!(()), false),
+        ReturnType::Type(_, ref ty) => {
+            let mut ty = (**ty).clone();
+            let boxed = if let Type::ImplTrait(TypeImplTrait { ref bounds, .. }) = ty {
+                ty = parse_quote!(#krate::__private::boxed::Box<dyn #bounds + #lifetime>);
+                true
+            } else {
+                false
+            };
+            let mut params = ::std::mem::take(&mut normalize.params);
+            normalize.visit_type_mut(&mut ty);
+            if !normalize.params.is_empty() {
+                return Err(Error::new(func.sig.output.span(), "#[curry] does not support nested `impl Trait` in return type"));
+            }
+            normalize.params.append(&mut params);
+            (ty, boxed)
+        }
+    };
+
+    let mut generics = func.sig.generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    for param in normalize.params {
+        generics.params.push(GenericParam::Type(param));
+    }
+    {
+        let where_clause = generics.make_where_clause();
+        for ty in &tys[..tys.len() - 1] {
+            let pred: WherePredicate = parse_quote!(#ty: #krate::__private::clone::Clone + #lifetime);
+            where_clause.predicates.push(pred);
+        }
+        if boxed {
+            // the boxed result may hold the last argument as well
+            let ty = &tys[tys.len() - 1];
+            where_clause.predicates.push(parse_quote!(#ty: #lifetime));
+        }
+    }
+
+    // innermost stage: all arguments are available
+    let last = tys.len() - 1;
+    let body = match companion {
+        Some(_) => {
+            let name = &func.sig.ident;
+            let values = args.iter().enumerate().map(|(i, a)| {
+                if i == last { quote!(#a) } else { quote!(#krate::__private::clone::Clone::clone(&#a)) }
+            });
+            quote!(#name(#(#values),*))
+        }
+        None => {
+            let block = &func.block;
+            let binds = pats.iter().zip(&tys).zip(&args).enumerate().map(|(i, ((p, t), a))| {
+                if i == last {
+                    quote!(let #p: #t = #a;)
+                } else {
+                    quote!(let #p: #t = #krate::__private::clone::Clone::clone(&#a);)
+                }
+            });
+            if boxed {
+                // keep `return` in the body away from the boxing
+                quote!({
+                    #(#binds)*
+                    #[allow(clippy::redundant_closure_call)]
+                    let ret = (move || #block)();
+                    #krate::__private::boxed::Box::new(ret)
+                })
+            } else {
+                quote!({ #(#binds)* #block })
+            }
+        }
+    };
+    let body = match companion {
+        Some(_) if boxed => quote!(#krate::__private::boxed::Box::new(#body)),
+        _ => body,
+    };
+    let (a, t) = (&args[last], &tys[last]);
+    let mut stage = quote!(move |#a: #t| -> #ret { #body });
+    let mut stage_ty = quote!(Fn(#t) -> #ret);
+
+    // wrap outwards, every stage clones what it captured into the next one
+    for k in (1..last).rev() {
+        let (a, t) = (&args[k], &tys[k]);
+        let captured = &args[..k];
+        stage = quote!(move |#a: #t| {
+            #(let #captured = #krate::__private::clone::Clone::clone(&#captured);)*
+            let stage: #krate::__private::boxed::Box<dyn #stage_ty + #lifetime> = #krate::__private::boxed::Box::new(#stage);
+            stage
+        });
+        stage_ty = quote!(Fn(#t) -> #krate::__private::boxed::Box<dyn #stage_ty + #lifetime>);
+    }
+
+    let attrs = match companion {
+        Some(_) => {
+            let doc = format!("Curried version of [`{}`]", func.sig.ident);
+            vec![parse_quote!(#[doc = #doc])]
+        }
+        None => func.attrs.clone(),
+    };
+    let vis = &func.vis;
+    let name = companion.unwrap_or(&func.sig.ident);
+    let (_, _, where_clause) = generics.split_for_impl();
+    let (a, t) = (&args[0], &tys[0]);
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #name #generics (#a: #t) -> impl #stage_ty + #lifetime #where_clause {
+            #stage
+        }
+    })
+}
@@ -0,0 +1,29 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Path of `pa` used by expansions, `::pa` unless given by `crate = path`,
+//! e.g. when the dependency is renamed or the macro is used inside `pa` itself.
+
+use syn::parse::{ParseStream, Result};
+use syn::Path;
+
+/// Path used when `crate = path` is not given
+pub fn default() -> Path {
+    parse_quote!(::pa)
+}
+
+/// Whether `crate = path` follows
+pub fn peek(input: ParseStream) -> bool {
+    input.peek(Token![crate]) && input.peek2(Token![=])
+}
+
+/// Parse `crate = path`
+pub fn parse(input: ParseStream) -> Result<Path> {
+    input.parse::<Token![crate]>()?;
+    input.parse::<Token![=]>()?;
+    input.call(Path::parse_mod_style)
+}
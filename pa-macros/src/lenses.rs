@@ -0,0 +1,62 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Expansion of `#[derive(Lenses)]`.
+//!
+//! Every field `x: A` of `struct S` gets an associated function `S::lens_x() -> FieldLens<S, A>`,
+//! tuple fields are named by position, e.g. `S::lens_0()`.
+//! The `Clone` bound is put under `for<'a>`, so a field which is not `Clone` only fails when its lens is used.
+//! `#[lenses(crate = path)]` on the struct sets the path of `pa`.
+
+use proc_macro2::{Span, TokenStream};
+use syn::ext::IdentExt;
+use syn::parse::{Error, Result};
+use syn::{parse2, Data, DeriveInput, Ident, Index, Member};
+
+use krate;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let input: DeriveInput = parse2(input)?;
+    let mut krate = krate::default();
+    for attr in &input.attrs {
+        if attr.path().is_ident("lenses") {
+            krate = attr.parse_args_with(krate::parse)?;
+        }
+    }
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(Error::new(Span::call_site(), "#[derive(Lenses)] only supports struct")),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let lenses = fields.iter().enumerate().map(|(i, field)| {
+        let (member, fn_name) = match field.ident {
+            Some(ref ident) => (Member::Named(ident.clone()), format!("lens_{}", ident.unraw())),
+            None => (Member::Unnamed(Index::from(i)), format!("lens_{}", i)),
+        };
+        let fn_name = Ident::new(&fn_name, Span::call_site());
+        let vis = &field.vis;
+        let ty = &field.ty;
+        let doc = format!("Lens of field `{}`", quote!(#member));
+        quote! {
+            #[doc = #doc]
+            #vis fn #fn_name() -> #krate::optics::FieldLens<Self, #ty> where for<'a> #ty: #krate::__private::clone::Clone {
+                #krate::optics::lens(
+                    (|s: &Self| #krate::__private::clone::Clone::clone(&s.#member)) as fn(&Self) -> #ty,
+                    (|mut s: Self, a: #ty| { s.#member = a; s }) as fn(Self, #ty) -> Self,
+                )
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#lenses)*
+        }
+    })
+}
@@ -0,0 +1,71 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Procedural macros of KaguyaRs, re-exported by `pa`.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+mod curry;
+mod krate;
+mod lenses;
+mod memoize;
+
+use proc_macro::TokenStream;
+
+/// Errors as `compile_error!` without path, as `::core` is not in the crate root of 2015 edition crates
+fn compile_error(e: syn::Error) -> proc_macro2::TokenStream {
+    e.into_iter()
+        .map(|e| {
+            let msg = e.to_string();
+            quote_spanned!(e.span()=> compile_error!(#msg);)
+        })
+        .collect()
+}
+
+/// Curry a function, `fn f(a: A, b: B, c: C) -> R` becomes `f(a)(b)(c)`.
+///
+/// `#[curry]` replaces the function, `#[curry(name)]` keeps it and adds curried `name`.
+/// The expansion refers `::pa`, `#[curry(crate = path)]` changes it, e.g. for a renamed dependency.
+/// Every argument except the last must be [`Clone`], and `impl Trait` in return position
+/// is boxed as `dyn Trait`. A partial application is a closure, so its generic types are
+/// fixed by the first call.
+///
+/// **Signature**: (A, B, C) -> R => A -> B -> C -> R
+#[proc_macro_attribute]
+pub fn curry(attr: TokenStream, item: TokenStream) -> TokenStream {
+    curry::expand(attr.into(), item.into())
+        .unwrap_or_else(compile_error)
+        .into()
+}
+
+/// Derive a `Lens` for every field of a struct, `x: A` gets `fn lens_x() -> FieldLens<Self, A>`.
+/// Tuple fields are named by position, e.g. `lens_0`. Fields must be [`Clone`] to be viewed.
+///
+/// The lenses are in `pa::optics`, which needs feature `alloc` of `pa`.
+/// The expansion refers `::pa`, `#[lenses(crate = path)]` changes it, e.g. for a renamed dependency.
+#[proc_macro_derive(Lenses, attributes(lenses))]
+pub fn lenses(input: TokenStream) -> TokenStream {
+    lenses::expand(input.into())
+        .unwrap_or_else(compile_error)
+        .into()
+}
+
+/// Cache results of a function by its arguments, recursive calls are cached as well.
+///
+/// Arguments must be `Hash + Eq + Clone` and result must be [`Clone`], generic functions and borrowed arguments
+/// are not supported as the cache is static. The cache is thread local, `#[memoize(sync)]` shares it by all threads.
+#[proc_macro_attribute]
+pub fn memoize(attr: TokenStream, item: TokenStream) -> TokenStream {
+    memoize::expand(attr.into(), item.into())
+        .unwrap_or_else(compile_error)
+        .into()
+}
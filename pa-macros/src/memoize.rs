@@ -0,0 +1,151 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Expansion of `#[memoize]`.
+//!
+//! `fn f(a: A, b: B) -> R` keeps its signature, the original body is moved into an inner function
+//! and results are cached in a static map keyed by `(A, B)`. Recursive calls go through `f`,
+//! so they hit the cache as well. The cache is thread local, or shared by a `Mutex` with `#[memoize(sync)]`.
+
+use proc_macro2::{Span, TokenStream};
+use syn::parse::{Error, Result};
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{parse2, FnArg, Ident, ItemFn, Lifetime, ReturnType, Type, TypeReference};
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let sync = if attr.is_empty() {
+        false
+    } else {
+        let mode: Ident = parse2(attr)?;
+        if mode != "sync" {
+            return Err(Error::new(mode.span(), "expected `#[memoize]` or `#[memoize(sync)]`"));
+        }
+        true
+    };
+    let func: ItemFn = parse2(item)?;
+    check(&func)?;
+
+    let sig = &func.sig;
+    let ret: Type = match sig.output {
+        ReturnType::Default => parse_quote!(()),
+        ReturnType::Type(_, ref ty) => (**ty).clone(),
+    };
+    let mut args: Vec<Ident> = Vec::new();
+    let mut tys: Vec<Type> = Vec::new();
+    for (i, input) in sig.inputs.iter().enumerate() {
+        if let FnArg::Typed(ref arg) = *input {
+            if let Type::ImplTrait(ref ty) = *arg.ty {
+                return Err(Error::new(ty.span(), "#[memoize] does not support `impl Trait` arguments"));
+            }
+            args.push(Ident::new(&format!("__pa_arg{}", i), Span::mixed_site()));
+            tys.push((*arg.ty).clone());
+        }
+    }
+
+    let mut inner = sig.clone();
+    inner.ident = Ident::new("__pa_memoized", Span::mixed_site());
+    let block = &func.block;
+
+    let mut outer = sig.clone();
+    for (input, arg) in outer.inputs.iter_mut().zip(&args) {
+        if let FnArg::Typed(ref mut typed) = *input {
+            typed.pat = parse_quote!(#arg);
+        }
+    }
+
+    let key = quote!((#(::std::clone::Clone::clone(&#args),)*));
+    let map = quote!(::std::collections::HashMap<(#(#tys,)*), #ret>);
+    let cached = if sync {
+        quote! {
+            static MEMO: ::std::sync::OnceLock<::std::sync::Mutex<#map>> = ::std::sync::OnceLock::new();
+            let memo = || MEMO.get_or_init(::std::default::Default::default)
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            let key = #key;
+            if let ::std::option::Option::Some(r) = memo().get(&key) {
+                return ::std::clone::Clone::clone(r);
+            }
+            let r = __pa_memoized(#(#args),*);
+            memo().insert(key, ::std::clone::Clone::clone(&r));
+            r
+        }
+    } else {
+        quote! {
+            ::std::thread_local! {
+                static MEMO: ::std::cell::RefCell<#map> = ::std::cell::RefCell::new(::std::collections::HashMap::new());
+            }
+            let key = #key;
+            if let ::std::option::Option::Some(r) = MEMO.with(|m| m.borrow().get(&key).cloned()) {
+                return r;
+            }
+            let r = __pa_memoized(#(#args),*);
+            MEMO.with(|m| m.borrow_mut().insert(key, ::std::clone::Clone::clone(&r)));
+            r
+        }
+    };
+
+    let attrs = &func.attrs;
+    let vis = &func.vis;
+    Ok(quote! {
+        #(#attrs)*
+        #vis #outer {
+            #inner #block
+            #cached
+        }
+    })
+}
+
+fn check(func: &ItemFn) -> Result<()> {
+    let sig = &func.sig;
+    if let Some(c) = sig.constness {
+        return Err(Error::new(c.span, "#[memoize] cannot be applied to `const fn`"));
+    }
+    if let Some(a) = sig.asyncness {
+        return Err(Error::new(a.span, "#[memoize] cannot be applied to `async fn`"));
+    }
+    if let Some(u) = sig.unsafety {
+        return Err(Error::new(u.span, "#[memoize] cannot be applied to `unsafe fn`"));
+    }
+    if let Some(ref v) = sig.variadic {
+        return Err(Error::new(v.span(), "#[memoize] cannot be applied to variadic functions"));
+    }
+    if let Some(FnArg::Receiver(r)) = sig.inputs.first() {
+        return Err(Error::new(r.span(), "#[memoize] cannot be applied to methods"));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new(sig.generics.span(), "#[memoize] cannot be applied to generic functions, as the cache is static"));
+    }
+    for input in &sig.inputs {
+        if let FnArg::Typed(ref arg) = *input {
+            let mut borrow = Borrow(None);
+            borrow.visit_type(&arg.ty);
+            if let Some(span) = borrow.0 {
+                return Err(Error::new(span, "#[memoize] does not support borrowed arguments, as the cache is static"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Find the first reference or lifetime in a type, keys of the static cache cannot borrow
+struct Borrow(Option<Span>);
+
+impl<'ast> Visit<'ast> for Borrow {
+    fn visit_type_reference(&mut self, r: &'ast TypeReference) {
+        if self.0.is_none() {
+            self.0 = Some(r.span());
+        }
+    }
+
+    fn visit_lifetime(&mut self, l: &'ast Lifetime) {
+        if self.0.is_none() {
+            self.0 = Some(l.span());
+        }
+        visit::visit_lifetime(self, l);
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use self::Either::{Left, Right};
+
+/// Either a value of `L` or a value of `R`, unlike [`Result`] no side is treated as failure.
+///
+/// Typeclass instances are biased to `Right`, i.e. [`Functor`](typeclass::Functor) maps the `Right` value
+/// and [`Monad`](typeclass::Monad) stops at the first `Left`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Check if it is `Left`
+    pub fn is_left(&self) -> bool {
+        match *self {
+            Left(_) => true,
+            Right(_) => false,
+        }
+    }
+
+    /// Check if it is `Right`
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+
+    /// Get the `Left` value, `None` if it is `Right`
+    pub fn left(self) -> Option<L> {
+        match self {
+            Left(l) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    /// Get the `Right` value, `None` if it is `Left`
+    pub fn right(self) -> Option<R> {
+        match self {
+            Left(_) => None,
+            Right(r) => Some(r),
+        }
+    }
+
+    /// Get the `Left` value, or a default value if it is `Right`
+    ///
+    /// # Arguments
+    ///
+    /// * `default`: L
+    pub fn left_or(self, default: L) -> L {
+        self.left().unwrap_or(default)
+    }
+
+    /// Get the `Right` value, or a default value if it is `Left`
+    ///
+    /// # Arguments
+    ///
+    /// * `default`: R
+    pub fn right_or(self, default: R) -> R {
+        self.right().unwrap_or(default)
+    }
+
+    /// Borrow the value inside
+    pub fn as_ref(&self) -> Either<&L, &R> {
+        match *self {
+            Left(ref l) => Left(l),
+            Right(ref r) => Right(r),
+        }
+    }
+
+    /// Map the `Left` value
+    ///
+    /// **Signature**: map_left :: Either L R -> (L -> M) -> Either M R
+    pub fn map_left<M>(self, f: impl FnOnce(L) -> M) -> Either<M, R> {
+        self.bimap(f, |r| r)
+    }
+
+    /// Map the `Right` value
+    ///
+    /// **Signature**: map_right :: Either L R -> (R -> S) -> Either L S
+    pub fn map_right<S>(self, f: impl FnOnce(R) -> S) -> Either<L, S> {
+        self.bimap(|l| l, f)
+    }
+
+    /// Map both sides
+    ///
+    /// **Signature**: bimap :: Either L R -> (L -> M) -> (R -> S) -> Either M S
+    pub fn bimap<M, S>(self, f: impl FnOnce(L) -> M, g: impl FnOnce(R) -> S) -> Either<M, S> {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(g(r)),
+        }
+    }
+
+    /// Reduce both sides into one type
+    ///
+    /// **Signature**: either :: Either L R -> (L -> T) -> (R -> T) -> T
+    pub fn either<T>(self, f: impl FnOnce(L) -> T, g: impl FnOnce(R) -> T) -> T {
+        match self {
+            Left(l) => f(l),
+            Right(r) => g(r),
+        }
+    }
+
+    /// Chain a computation on the `Right` value
+    ///
+    /// **Signature**: and_then :: Either L R -> (R -> Either L S) -> Either L S
+    pub fn and_then<S>(self, f: impl FnOnce(R) -> Either<L, S>) -> Either<L, S> {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => f(r),
+        }
+    }
+
+    /// Swap `Left` and `Right`
+    ///
+    /// **Signature**: flip :: Either L R -> Either R L
+    pub fn flip(self) -> Either<R, L> {
+        match self {
+            Left(l) => Right(l),
+            Right(r) => Left(r),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    /// Get the value inside when both sides are same type
+    pub fn into_inner(self) -> T {
+        match self {
+            Left(x) | Right(x) => x,
+        }
+    }
+}
+
+/// `Ok` becomes `Right`, `Err` becomes `Left`
+impl<L, R> From<Result<R, L>> for Either<L, R> {
+    fn from(r: Result<R, L>) -> Self {
+        match r {
+            Ok(r) => Right(r),
+            Err(l) => Left(l),
+        }
+    }
+}
+
+/// `Right` becomes `Ok`, `Left` becomes `Err`
+impl<L, R> From<Either<L, R>> for Result<R, L> {
+    fn from(e: Either<L, R>) -> Self {
+        match e {
+            Left(l) => Err(l),
+            Right(r) => Ok(r),
+        }
+    }
+}
+
+/// Reduce an [`Either`] by a function for each side
+///
+/// # Arguments
+///
+/// * `f`: f :: L -> T
+/// * `g`: g :: R -> T
+/// * `e`: [`Either`] L R
+pub fn either<L, R, T>(f: impl FnOnce(L) -> T, g: impl FnOnce(R) -> T, e: Either<L, R>) -> T {
+    e.either(f, g)
+}
+
+/// Get all `Left` values lazily
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] (Either L R)
+pub fn lefts<L, R>(it: impl Iterator<Item=Either<L, R>>) -> impl Iterator<Item=L> {
+    it.filter_map(Either::left)
+}
+
+/// Get all `Right` values lazily
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] (Either L R)
+pub fn rights<L, R>(it: impl Iterator<Item=Either<L, R>>) -> impl Iterator<Item=R> {
+    it.filter_map(Either::right)
+}
+
+/// Split `Left` and `Right` values, keeping the order
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] (Either L R)
+#[cfg(feature = "alloc")]
+pub fn partition_eithers<L, R>(it: impl Iterator<Item=Either<L, R>>) -> (Vec<L>, Vec<R>) {
+    let mut ls = Vec::new();
+    let mut rs = Vec::new();
+    for e in it {
+        match e {
+            Left(l) => ls.push(l),
+            Right(r) => rs.push(r),
+        }
+    }
+    (ls, rs)
+}
@@ -0,0 +1,20 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module adt provide algebraic data types which are not in std, with typeclass instances.
+
+pub mod either;
+pub mod validated;
+#[cfg(feature = "alloc")]
+pub mod non_empty;
+
+pub use self::either::{Either, either, lefts, rights};
+#[cfg(feature = "alloc")]
+pub use self::either::partition_eithers;
+pub use self::validated::{Validated, validate};
+#[cfg(feature = "alloc")]
+pub use self::non_empty::{NonEmptyVec, NonEmptyList, EmptyError};
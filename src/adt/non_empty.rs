@@ -0,0 +1,357 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+use core::ops::Deref;
+use core::slice;
+use alloc::vec::{self, Vec};
+
+use crate::persistent::List;
+use crate::persistent::list::Iter;
+use crate::typeclass::monoid::Semigroup;
+
+/// Error of building a non-empty collection from nothing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyError;
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("collection is empty")
+    }
+}
+
+impl Error for EmptyError {}
+
+/// [`Vec`] with at least one element, so `head`, `last`, `max` and `min` are total.
+///
+/// It derefs to slice, the inherent methods shadow the partial ones of slice, e.g. [`NonEmptyVec::last`].
+/// It is not [`Ord`] on purpose, otherwise `Ord::max` would shadow [`NonEmptyVec::max`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    /// Vec with one element
+    ///
+    /// # Arguments
+    ///
+    /// * `head`: T
+    pub fn new(head: T) -> Self {
+        NonEmptyVec(vec![head])
+    }
+
+    /// Build from the first element and the rest
+    ///
+    /// # Arguments
+    ///
+    /// * `head`: T
+    /// * `tail`: [`Vec`] T
+    pub fn from_parts(head: T, mut tail: Vec<T>) -> Self {
+        tail.insert(0, head);
+        NonEmptyVec(tail)
+    }
+
+    /// Build from a [`Vec`], `None` if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `v`: [`Vec`] T
+    pub fn from_vec(v: Vec<T>) -> Option<Self> {
+        if v.is_empty() { None } else { Some(NonEmptyVec(v)) }
+    }
+
+    /// Build from an [`Iterator`], `None` if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `it`: [`Iterator`] T
+    pub fn try_from_iter(it: impl IntoIterator<Item=T>) -> Option<Self> {
+        NonEmptyVec::from_vec(it.into_iter().collect())
+    }
+
+    /// Getting the first element
+    pub fn head(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Getting all elements except first
+    pub fn tail(&self) -> &[T] {
+        &self.0[1..]
+    }
+
+    /// Getting the last element
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Getting all elements except the last one
+    pub fn init(&self) -> &[T] {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Get max element, the last one if several are equally maximum
+    pub fn max(&self) -> &T where T: Ord {
+        self.0.iter().max().unwrap_or_else(|| self.head())
+    }
+
+    /// Get min element, the first one if several are equally minimum
+    pub fn min(&self) -> &T where T: Ord {
+        self.0.iter().min().unwrap_or_else(|| self.head())
+    }
+
+    /// Count of elements, never 0
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always `false`, for consistency with other collections
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Append an element to the back
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn push(&mut self, x: T) {
+        self.0.push(x)
+    }
+
+    /// Remove the last element, `None` if it is the only one
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.len() > 1 { self.0.pop() } else { None }
+    }
+
+    /// Map every element, the result is non-empty as well
+    ///
+    /// **Signature**: map :: NonEmptyVec T -> (T -> U) -> NonEmptyVec U
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> NonEmptyVec<U> {
+        NonEmptyVec(self.0.into_iter().map(f).collect())
+    }
+
+    /// Fold from the beginning without initial value, the first element is used instead
+    ///
+    /// **Signature**: fold1 :: NonEmptyVec T -> (T -> T -> T) -> T
+    pub fn fold1(self, f: impl FnMut(T, T) -> T) -> T {
+        let mut it = self.0.into_iter();
+        let head = it.next().expect("NonEmptyVec is never empty");
+        it.fold(head, f)
+    }
+
+    /// Combine all elements by [`Semigroup`], no [`Monoid`](typeclass::Monoid) is needed
+    ///
+    /// **Signature**: reduce :: Semigroup T => NonEmptyVec T -> T
+    pub fn reduce(self) -> T where T: Semigroup {
+        self.fold1(T::mappend)
+    }
+
+    /// Get the inner [`Vec`]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVec<T> {
+    fn extend<I: IntoIterator<Item=T>>(&mut self, it: I) {
+        self.0.extend(it)
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = EmptyError;
+
+    fn try_from(v: Vec<T>) -> Result<Self, EmptyError> {
+        NonEmptyVec::from_vec(v).ok_or(EmptyError)
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(v: NonEmptyVec<T>) -> Self {
+        v.0
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.0.iter()
+    }
+}
+
+/// Persistent [`List`] with at least one element, so `head`, `last`, `max` and `min` are total.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NonEmptyList<T>(List<T>);
+
+impl<T> NonEmptyList<T> {
+    /// List with one element
+    ///
+    /// # Arguments
+    ///
+    /// * `head`: T
+    pub fn new(head: T) -> Self {
+        NonEmptyList(List::new().cons(head))
+    }
+
+    /// Prepend an element to a possibly empty [`List`]
+    ///
+    /// # Arguments
+    ///
+    /// * `head`: T
+    /// * `tail`: [`List`] T
+    pub fn from_parts(head: T, tail: &List<T>) -> Self {
+        NonEmptyList(tail.cons(head))
+    }
+
+    /// Build from a [`List`], `None` if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `l`: [`List`] T
+    pub fn from_list(l: List<T>) -> Option<Self> {
+        if l.is_empty() { None } else { Some(NonEmptyList(l)) }
+    }
+
+    /// Build from an [`Iterator`], `None` if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `it`: [`Iterator`] T
+    pub fn try_from_iter(it: impl IntoIterator<Item=T>) -> Option<Self> {
+        NonEmptyList::from_list(it.into_iter().collect())
+    }
+
+    /// Prepend an element
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn cons(&self, x: T) -> Self {
+        NonEmptyList(self.0.cons(x))
+    }
+
+    /// Getting the first element
+    pub fn head(&self) -> &T {
+        self.0.head().expect("NonEmptyList is never empty")
+    }
+
+    /// Getting all elements except first, which may be empty
+    pub fn tail(&self) -> List<T> {
+        self.0.tail().unwrap_or_default()
+    }
+
+    /// Getting the last element
+    pub fn last(&self) -> &T {
+        self.0.iter().last().unwrap_or_else(|| self.head())
+    }
+
+    /// Get max element, the last one if several are equally maximum
+    pub fn max(&self) -> &T where T: Ord {
+        self.0.iter().max().unwrap_or_else(|| self.head())
+    }
+
+    /// Get min element, the first one if several are equally minimum
+    pub fn min(&self) -> &T where T: Ord {
+        self.0.iter().min().unwrap_or_else(|| self.head())
+    }
+
+    /// Count of elements, never 0
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always `false`, for consistency with other collections
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// [`Iterator`] over references of elements from the head
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Map every element into a new list
+    ///
+    /// **Signature**: map :: NonEmptyList T -> (&T -> U) -> NonEmptyList U
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> NonEmptyList<U> {
+        NonEmptyList(self.0.iter().map(f).collect())
+    }
+
+    /// Fold from the beginning without initial value, the first element is used instead
+    ///
+    /// **Signature**: fold1 :: NonEmptyList T -> (T -> T -> T) -> T
+    pub fn fold1(&self, f: impl FnMut(T, T) -> T) -> T where T: Clone {
+        self.0.iter().skip(1).cloned().fold(self.head().clone(), f)
+    }
+
+    /// Combine all elements by [`Semigroup`], no [`Monoid`](typeclass::Monoid) is needed
+    ///
+    /// **Signature**: reduce :: Semigroup T => NonEmptyList T -> T
+    pub fn reduce(&self) -> T where T: Semigroup + Clone {
+        self.fold1(T::mappend)
+    }
+
+    /// Get the inner [`List`]
+    pub fn into_list(self) -> List<T> {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for NonEmptyList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T> TryFrom<List<T>> for NonEmptyList<T> {
+    type Error = EmptyError;
+
+    fn try_from(l: List<T>) -> Result<Self, EmptyError> {
+        NonEmptyList::from_list(l).ok_or(EmptyError)
+    }
+}
+
+impl<T> From<NonEmptyList<T>> for List<T> {
+    fn from(l: NonEmptyList<T>) -> Self {
+        l.0
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for NonEmptyList<T> {
+    fn from(v: NonEmptyVec<T>) -> Self {
+        NonEmptyList(v.0.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.0.iter()
+    }
+}
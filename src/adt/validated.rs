@@ -0,0 +1,135 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use self::Validated::{Valid, Invalid};
+
+/// Result of validation, which accumulates all errors instead of stopping at the first one.
+///
+/// [`Applicative`](typeclass::Applicative) combines errors by [`Semigroup`](typeclass::Semigroup) of `E`,
+/// e.g. [`Vec`], so [traverse_iter](typeclass::traverse_iter) reports every invalid element.
+/// It is not a [`Monad`](typeclass::Monad) as binding must stop at the first error, use [`Validated::and_then`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validated<E, T> {
+    Valid(T),
+    Invalid(E),
+}
+
+impl<E, T> Validated<E, T> {
+    /// Check if it is `Valid`
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Valid(_) => true,
+            Invalid(_) => false,
+        }
+    }
+
+    /// Check if it is `Invalid`
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// Get the valid value, `None` if it is `Invalid`
+    pub fn valid(self) -> Option<T> {
+        match self {
+            Valid(x) => Some(x),
+            Invalid(_) => None,
+        }
+    }
+
+    /// Get the errors, `None` if it is `Valid`
+    pub fn invalid(self) -> Option<E> {
+        match self {
+            Valid(_) => None,
+            Invalid(e) => Some(e),
+        }
+    }
+
+    /// Borrow the value inside
+    pub fn as_ref(&self) -> Validated<&E, &T> {
+        match *self {
+            Valid(ref x) => Valid(x),
+            Invalid(ref e) => Invalid(e),
+        }
+    }
+
+    /// Map the valid value
+    ///
+    /// **Signature**: map :: Validated E T -> (T -> U) -> Validated E U
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Validated<E, U> {
+        match self {
+            Valid(x) => Valid(f(x)),
+            Invalid(e) => Invalid(e),
+        }
+    }
+
+    /// Map the errors
+    ///
+    /// **Signature**: map_err :: Validated E T -> (E -> F) -> Validated F T
+    pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> Validated<F, T> {
+        match self {
+            Valid(x) => Valid(x),
+            Invalid(e) => Invalid(f(e)),
+        }
+    }
+
+    /// Chain a validation depending on the valid value, stops at the first error
+    ///
+    /// **Signature**: and_then :: Validated E T -> (T -> Validated E U) -> Validated E U
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> Validated<E, U>) -> Validated<E, U> {
+        match self {
+            Valid(x) => f(x),
+            Invalid(e) => Invalid(e),
+        }
+    }
+
+    /// Get the valid value, or a default value if it is `Invalid`
+    ///
+    /// # Arguments
+    ///
+    /// * `default`: T
+    pub fn unwrap_or(self, default: T) -> T {
+        self.valid().unwrap_or(default)
+    }
+
+    /// Convert into [`Result`]
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            Valid(x) => Ok(x),
+            Invalid(e) => Err(e),
+        }
+    }
+}
+
+impl<E, T> From<Result<T, E>> for Validated<E, T> {
+    fn from(r: Result<T, E>) -> Self {
+        match r {
+            Ok(x) => Valid(x),
+            Err(e) => Invalid(e),
+        }
+    }
+}
+
+impl<E, T> From<Validated<E, T>> for Result<T, E> {
+    fn from(v: Validated<E, T>) -> Self {
+        v.into_result()
+    }
+}
+
+/// Check a value by a predicate, the error is built only if the check fails
+///
+/// # Arguments
+///
+/// * `p`: p :: &T -> [`bool`]
+/// * `err`: err :: &T -> E
+/// * `x`: T
+pub fn validate<E, T>(p: impl FnOnce(&T) -> bool, err: impl FnOnce(&T) -> E, x: T) -> Validated<E, T> {
+    if p(&x) {
+        Valid(x)
+    } else {
+        Invalid(err(&x))
+    }
+}
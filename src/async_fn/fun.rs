@@ -0,0 +1,134 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+
+use futures::future::{self, FutureExt};
+use futures::stream::{self, Stream, StreamExt};
+
+/// Used for map [`Stream`]<T> to [`Stream`]<U>
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> U
+/// * `s`: [`Stream`] T
+pub fn map<T, U>(f: impl FnMut(T) -> U, s: impl Stream<Item=T>) -> impl Stream<Item=U> {
+    s.map(f)
+}
+
+/// Used for filter [`Stream`]<T>
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> [`bool`], function to filter item
+/// * `s`: [`Stream`] T
+pub fn filter<T>(f: impl Fn(&T) -> bool, s: impl Stream<Item=T>) -> impl Stream<Item=T> {
+    s.filter(move |x| future::ready(f(x)))
+}
+
+/// Used for reverse filter [`Stream`]<T>
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> [`bool`], function to reverse filter item
+/// * `s`: [`Stream`] T
+pub fn filter_not<T>(f: impl Fn(&T) -> bool, s: impl Stream<Item=T>) -> impl Stream<Item=T> {
+    s.filter(move |x| future::ready(!f(x)))
+}
+
+/// Used for fold the stream from the beginning with init value and fold function
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding, must be same type with final result
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `s`: [`Stream`] T
+pub fn foldl<T, R>(init: R, f: impl Fn(R, T) -> R, s: impl Stream<Item=T>) -> impl Future<Output=R> {
+    s.fold(init, move |acc, x| future::ready(f(acc, x)))
+}
+
+/// Take first n elements of [`Stream`]<T>
+///
+/// # Arguments
+///
+/// * `n`: count of elements
+/// * `s`: [`Stream`] T
+pub fn take<T>(n: usize, s: impl Stream<Item=T>) -> impl Stream<Item=T> {
+    s.take(n)
+}
+
+/// Skip first n elements of [`Stream`]<T>
+///
+/// # Arguments
+///
+/// * `n`: count of elements
+/// * `s`: [`Stream`] T
+pub fn skip<T>(n: usize, s: impl Stream<Item=T>) -> impl Stream<Item=T> {
+    s.skip(n)
+}
+
+/// Zip two streams into [`Stream`] of pairs, ends with the shorter one
+///
+/// # Arguments
+///
+/// * `a`: [`Stream`] A
+/// * `b`: [`Stream`] B
+pub fn zip<A, B>(a: impl Stream<Item=A>, b: impl Stream<Item=B>) -> impl Stream<Item=(A, B)> {
+    a.zip(b)
+}
+
+/// Zip two streams by function, ends with the shorter one
+///
+/// # Arguments
+///
+/// * `f`: f :: (A, B) -> R
+/// * `a`: [`Stream`] A
+/// * `b`: [`Stream`] B
+pub fn zip_with<A, B, R>(f: impl Fn(A, B) -> R, a: impl Stream<Item=A>, b: impl Stream<Item=B>) -> impl Stream<Item=R> {
+    a.zip(b).map(move |(x, y)| f(x, y))
+}
+
+/// [foldl] which yields every intermediate result, starting with `init`
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding, the first element
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `s`: [`Stream`] T
+pub fn scan<T, R: Clone>(init: R, f: impl Fn(R, T) -> R, s: impl Stream<Item=T>) -> impl Stream<Item=R> {
+    let rest = s.scan(Some(init.clone()), move |acc, x| {
+        let r = acc.take().map(|a| f(a, x));
+        *acc = r.clone();
+        future::ready(r)
+    });
+    stream::once(future::ready(init)).chain(rest)
+}
+
+/// Group elements by key into a [`HashMap`], elements of a group keep their order
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> K, key of element
+/// * `s`: [`Stream`] T
+pub fn group_by<T, K: Hash + Eq>(f: impl Fn(&T) -> K, s: impl Stream<Item=T>) -> impl Future<Output=HashMap<K, Vec<T>>> {
+    s.fold(HashMap::new(), move |mut ret: HashMap<K, Vec<T>>, x| {
+        ret.entry(f(&x)).or_default().push(x);
+        future::ready(ret)
+    })
+}
+
+/// Apply an async function to the output of a future, used by `async_pipe!`
+///
+/// # Arguments
+///
+/// * `fut`: [`Future`] T
+/// * `f`: f :: T -> [`Future`] R
+pub fn then<T, R: Future>(fut: impl Future<Output=T>, f: impl FnOnce(T) -> R) -> impl Future<Output=R::Output> {
+    fut.then(f)
+}
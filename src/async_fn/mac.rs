@@ -0,0 +1,51 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// [pipe!](crate::pipe) of async functions, every function returns a [`Future`](std::future::Future)
+/// which is awaited before calling the next one. The order is last-in-last-invoke.
+///
+/// Syntax:
+/// async_pipe!(f, g, h) = |v| async { h(g(f(v).await).await).await }
+///
+/// # Arguments
+///
+/// * `args` - async function or closure returning future separated by comma(,)
+#[macro_export] macro_rules! async_pipe {
+    (@NEXT $v:expr,$f:expr,$($rest:expr),*) => {
+        $crate::async_pipe!(@NEXT $crate::async_fn::then($v, $f), $($rest),*)
+    };
+    (@NEXT $v:expr,$last:expr) => {
+        $crate::async_fn::then($v, $last)
+    };
+    ($f:expr) => {
+        |v| $f(v)
+    };
+    ($f:expr,$($rest:expr),*) => {
+        |v| {$crate::async_pipe!(@NEXT $f(v), $($rest),*)}
+    };
+}
+
+/// [compose!](crate::compose) of async functions, see [async_pipe!](crate::async_pipe).
+/// The order is last-in-first-invoke.
+///
+/// # Arguments
+///
+/// * `args` - async function or closure returning future separated by comma(,)
+#[macro_export] macro_rules! async_compose {
+    (@NEXT [$($fs:expr),*],$f:expr,$($remain_fs:expr),*) => {
+        $crate::async_compose!(@NEXT [$f,$($fs),*],$($remain_fs),*)
+    };
+    (@NEXT [$($fs:expr),*],$f:expr) => {
+        $crate::async_pipe!($f,$($fs),*)
+    };
+    ($f:expr) => {
+        $crate::async_pipe!($f)
+    };
+    ($f:expr,$($fs:expr),*) => {
+        $crate::async_compose!(@NEXT [$f],$($fs),*)
+    };
+}
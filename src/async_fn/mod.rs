@@ -0,0 +1,29 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module async_fn provide basic functions over [`Stream`](futures::Stream), enabled by feature `async_fn`.
+//!
+//! Functions mirror [basic_fn::fun](crate::basic_fn::fun) with plain closures, they return a lazy
+//! [`Stream`](futures::Stream), or a [`Future`](std::future::Future) if they consume the stream.
+//! `async_pipe!` and `async_compose!` chain async functions. They run on any executor:
+//!
+//! ```
+//! # #[macro_use] extern crate pa;
+//! # extern crate futures;
+//! # use futures::executor::block_on;
+//! # use futures::stream;
+//! # use pa::async_fn::*;
+//! # fn main() {
+//! let evens = filter(|x| x % 2 == 0, stream::iter(1..=10));
+//! assert_eq!(block_on(foldl(0, |acc, x| acc + x, evens)), 30);
+//! # }
+//! ```
+
+pub mod fun;
+pub mod mac;
+
+pub use self::fun::{map, filter, filter_not, foldl, take, skip, zip, zip_with, scan, group_by, then};
@@ -0,0 +1,146 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lazy [`Iterator`] adapters returned by functions in [fun](basic_fn::fun).
+
+use core::iter::Chain;
+use core::option;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// [`Iterator`] of all elements except the last one, created by [init](basic_fn::fun::init).
+///
+/// One element is looked ahead to know whether current one is the last.
+pub struct Init<I: Iterator> {
+    iter: I,
+    next: Option<I::Item>,
+    back_dropped: bool,
+}
+
+impl<I: Iterator> Init<I> {
+    pub(crate) fn new(mut iter: I) -> Option<Self> {
+        let next = iter.next()?;
+        Some(Init { iter, next: Some(next), back_dropped: false })
+    }
+}
+
+impl<I: Iterator> Iterator for Init<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let current = self.next.take()?;
+        match self.iter.next() {
+            Some(next) => {
+                self.next = Some(next);
+                Some(current)
+            }
+            // current one is the last unless it is dropped from the back already
+            None if self.back_dropped => Some(current),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let next = self.next.is_some() as usize;
+        let dropped = (!self.back_dropped && next == 1) as usize;
+        (
+            lower.saturating_add(next).saturating_sub(dropped),
+            upper.and_then(|upper| upper.checked_add(next)).map(|upper| upper.saturating_sub(dropped)),
+        )
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Init<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        if !self.back_dropped {
+            self.back_dropped = true;
+            if self.iter.next_back().is_none() {
+                // the looked ahead one is the last
+                self.next = None;
+                return None;
+            }
+        }
+        match self.iter.next_back() {
+            Some(x) => Some(x),
+            None => self.next.take(),
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Init<I> {}
+
+/// [`Iterator`] of runs of consecutive elements, created by [group_with](basic_fn::fun::group_with).
+///
+/// One element is looked ahead as the start of next run.
+#[cfg(feature = "alloc")]
+pub struct GroupWith<I: Iterator, F> {
+    iter: I,
+    f: F,
+    next: Option<I::Item>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, F> GroupWith<I, F> {
+    pub(crate) fn new(f: F, iter: I) -> Self {
+        GroupWith { iter, f, next: None }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> Iterator for GroupWith<I, F> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let first = self.next.take().or_else(|| self.iter.next())?;
+        let mut group = vec![first];
+        for x in self.iter.by_ref() {
+            if (self.f)(&group[group.len() - 1], &x) {
+                group.push(x);
+            } else {
+                self.next = Some(x);
+                break;
+            }
+        }
+        Some(group)
+    }
+}
+
+/// [`Iterator`] of chunks with n elements, created by [split_every](basic_fn::fun::split_every).
+///
+/// The last chunk may be shorter.
+#[cfg(feature = "alloc")]
+pub struct SplitEvery<I> {
+    iter: I,
+    n: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> SplitEvery<I> {
+    pub(crate) fn new(n: usize, iter: I) -> Self {
+        assert!(n > 0, "split_every needs a positive chunk size");
+        SplitEvery { iter, n }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for SplitEvery<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.n).collect();
+        if chunk.is_empty() { None } else { Some(chunk) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.div_ceil(self.n), upper.map(|upper| upper.div_ceil(self.n)))
+    }
+}
+
+/// Rest of [`Iterator`] after [split_when](basic_fn::fun::split_when), starting from the matched element.
+pub type SplitWhen<I> = Chain<option::IntoIter<<I as Iterator>::Item>, I>;
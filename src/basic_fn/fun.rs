@@ -5,10 +5,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::iter::{Product, Sum, Zip};
-use std::ops::{Rem, Neg, Add, Sub, Div, Mul};
-use std::hash::Hash;
-use std::cmp::Ordering;
+use core::iter::{self, Product, Sum, Zip, Skip, Take, Rev, Chain, Map};
+use core::ops::{Rem, Neg, Add, Sub, Div, Mul, ControlFlow};
+use core::hash::Hash;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::adapter::Init;
+#[cfg(feature = "alloc")]
+use super::adapter::{GroupWith, SplitEvery, SplitWhen};
+#[cfg(feature = "alloc")]
+use crate::lazy::Trampoline;
 
 /// Used for data projection via mapping function.
 /// 
@@ -40,7 +55,8 @@ pub fn foldl<T,R>(init: R, f: impl Fn(R,T) -> R, it: impl DoubleEndedIterator<It
     it.fold(init, f)
 }
 
-/// Used for fold the double end iterator from the end with init value and fold function
+/// Used for fold the double end iterator from the end with init value and fold function,
+/// see [foldr_lazy] for single-ended or infinite [`Iterator`]
 /// 
 /// # Arguments
 /// 
@@ -51,6 +67,153 @@ pub fn foldr<T,R>(init: R, f: impl Fn(R,T) -> R, it: impl DoubleEndedIterator<It
     it.rev().fold(init, f)
 }
 
+/// Used for fold any iterator from the end lazily, which works for infinite [`Iterator`] if `f` short-circuits.
+/// `f` gets the folded rest as a suspended [`Trampoline`], not running it stops pulling the iterator,
+/// and mapping it keeps the fold stack-safe.
+///
+/// # Arguments
+///
+/// * `init`: result of folding empty iterator
+/// * `f`: f :: (T, [`Trampoline`] R) -> [`Trampoline`] R, fold function
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn foldr_lazy<'a, T, R, I, F>(init: R, f: F, it: I) -> R
+    where R: 'a, I: Iterator<Item=T> + 'a, F: Fn(T, Trampoline<'a, R>) -> Trampoline<'a, R> + 'a
+{
+    fn go<'a, T, R, I, F>(mut it: I, f: Rc<F>, init: R) -> Trampoline<'a, R>
+        where R: 'a, I: Iterator<Item=T> + 'a, F: Fn(T, Trampoline<'a, R>) -> Trampoline<'a, R> + 'a
+    {
+        Trampoline::defer(move || match it.next() {
+            Some(x) => f(x, go(it, f.clone(), init)),
+            None => Trampoline::done(init),
+        })
+    }
+    go(it, Rc::new(f), init).run()
+}
+
+/// Used for fold the iterator from the beginning until `f` breaks, the rest of the iterator is not pulled
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding, must be same type with final result
+/// * `f`: f :: (R, T) -> [`ControlFlow`] R R, `Break` stops folding with its value
+/// * `it`: [`Iterator`] T
+pub fn fold_while<T, R>(init: R, f: impl Fn(R, T) -> ControlFlow<R, R>, mut it: impl Iterator<Item=T>) -> R {
+    match it.try_fold(init, f) {
+        ControlFlow::Continue(r) | ControlFlow::Break(r) => r,
+    }
+}
+
+/// Fold elements of each group from the beginning into a [`HashMap`] by key, i.e. `reduceBy` in Ramda
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding of every group
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `key`: key :: &T -> K, key of element
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn foldl_by<T, R, K>(init: R, f: impl Fn(R, T) -> R, key: impl Fn(&T) -> K, it: impl Iterator<Item=T>) -> HashMap<K, R>
+    where R: Clone, K: Hash + Eq
+{
+    let mut ret: HashMap<K, R> = HashMap::new();
+    for x in it {
+        let k = key(&x);
+        let acc = ret.remove(&k).unwrap_or_else(|| init.clone());
+        ret.insert(k, f(acc, x));
+    }
+    ret
+}
+
+/// Fold elements of each group from the end into a [`HashMap`] by key
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding of every group
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `key`: key :: &T -> K, key of element
+/// * `it`: [`DoubleEndedIterator`] T
+#[cfg(feature = "std")]
+pub fn foldr_by<T, R, K>(init: R, f: impl Fn(R, T) -> R, key: impl Fn(&T) -> K, it: impl DoubleEndedIterator<Item=T>) -> HashMap<K, R>
+    where R: Clone, K: Hash + Eq
+{
+    foldl_by(init, f, key, it.rev())
+}
+
+/// Lazy [foldl] which yields every intermediate result, starting with `init`
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding, the first element
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `it`: [`Iterator`] T
+///
+/// **Signature**: R -> ((R, T) -> R) -> [`Iterator`] T -> [`Iterator`] R
+pub fn scan<T, R: Clone>(init: R, f: impl Fn(R, T) -> R, mut it: impl Iterator<Item=T>) -> impl Iterator<Item=R> {
+    let mut acc = Some(init);
+    iter::from_fn(move || {
+        let r = acc.take()?;
+        if let Some(x) = it.next() {
+            acc = Some(f(r.clone(), x));
+        }
+        Some(r)
+    })
+}
+
+/// Map elements from the beginning while threading a state through, returning the final state and mapped elements
+///
+/// # Arguments
+///
+/// * `init`: initial state
+/// * `f`: f :: (S, T) -> (S, U)
+/// * `it`: [`Iterator`] T
+///
+/// **Signature**: S -> ((S, T) -> (S, U)) -> [`Iterator`] T -> (S, [`Vec`] U)
+#[cfg(feature = "alloc")]
+pub fn map_accum<T, S, U>(init: S, f: impl Fn(S, T) -> (S, U), it: impl Iterator<Item=T>) -> (S, Vec<U>) {
+    let mut ret = Vec::new();
+    let acc = it.fold(init, |acc, x| {
+        let (acc, y) = f(acc, x);
+        ret.push(y);
+        acc
+    });
+    (acc, ret)
+}
+
+/// [map_accum] from the end, mapped elements keep the order of `it`
+///
+/// # Arguments
+///
+/// * `init`: initial state
+/// * `f`: f :: (S, T) -> (S, U)
+/// * `it`: [`DoubleEndedIterator`] T
+///
+/// **Signature**: S -> ((S, T) -> (S, U)) -> [`DoubleEndedIterator`] T -> (S, [`Vec`] U)
+#[cfg(feature = "alloc")]
+pub fn map_accum_right<T, S, U>(init: S, f: impl Fn(S, T) -> (S, U), it: impl DoubleEndedIterator<Item=T>) -> (S, Vec<U>) {
+    let (acc, mut ret) = map_accum(init, f, it.rev());
+    ret.reverse();
+    (acc, ret)
+}
+
+/// Generate elements lazily from a seed until `f` returns `None`,
+/// see [lazy::unfold](crate::lazy::unfold) for a memoised [`Stream`](crate::lazy::Stream)
+///
+/// # Arguments
+///
+/// * `seed`: initial seed
+/// * `f`: f :: S -> [`Option`] (T, S), next element and seed
+///
+/// **Signature**: S -> (S -> [`Option`] (T, S)) -> [`Iterator`] T
+pub fn unfold<S, T>(seed: S, f: impl Fn(S) -> Option<(T, S)>) -> impl Iterator<Item=T> {
+    let mut seed = Some(seed);
+    iter::from_fn(move || {
+        let (x, next) = f(seed.take()?)?;
+        seed = Some(next);
+        Some(x)
+    })
+}
+
 /// Used for filter [`Iterator`]<T>
 /// 
 /// # Arguments
@@ -71,7 +234,8 @@ pub fn filter_not<T>(f: impl Fn(&T) -> bool, it: impl Iterator<Item=T>) -> impl
     it.filter(move |x| !f(x))
 }
 
-/// Getting the first element of [`Iterator`]<T>
+/// Getting the first element of [`Iterator`]<T>, `None` if it is empty.
+/// [`NonEmptyVec`](adt::NonEmptyVec) provides total `head`, `last`, `min` and `max`.
 ///
 /// # Arguments
 ///
@@ -80,18 +244,24 @@ pub fn head<T>(mut it: impl Iterator<Item=T>) -> Option<T> {
     it.next()
 }
 
+/// Getting all elements of [`Iterator`]<T> except first, lazily.
+/// `None` if the [`Iterator`] is empty.
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] T
+pub fn tail<I: Iterator>(mut it: I) -> Option<I> {
+    it.next().map(|_| it)
+}
+
 /// Getting all elements of [`Iterator`]<T> except first as [`Vec`]<T>
 ///
 /// # Arguments
 ///
 /// * `it`: [`Iterator`] T
-pub fn tail<T>(mut it: impl Iterator<Item=T>) -> Option<Vec<T>> {
-    if let Some(_) = it.next() {
-        let mut ret = Vec::new();
-        ret.extend(it);
-        return Some(ret);
-    }
-    None
+#[cfg(feature = "alloc")]
+pub fn tail_vec<T>(it: impl Iterator<Item=T>) -> Option<Vec<T>> {
+    tail(it).map(Iterator::collect)
 }
 
 /// Getting last element of [`Iterator`]<T>
@@ -107,20 +277,34 @@ pub fn last<T>(it: impl Iterator<Item=T>) -> Option<T> {
     ret
 }
 
-/// Getting all elements of [`Iterator`]<T> except the last one
+/// Getting all elements of [`Iterator`]<T> except the last one, lazily.
+/// `None` if the [`Iterator`] is empty.
 ///
 /// # Arguments
 ///
 /// * `it`: [`Iterator`] T
-pub fn init<T>(it: impl Iterator<Item=T>) -> Option<Vec<T>> {
-    let mut ret = Vec::new();
-    ret.extend(it);
-    let size = ret.len();
-    if size == 0 {
-        return None;
-    }
-    ret.remove(size-1);
-    Some(ret)
+pub fn init<I: Iterator>(it: I) -> Option<Init<I>> {
+    Init::new(it)
+}
+
+/// Getting all elements of [`Iterator`]<T> except the last one as [`Vec`]<T>
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn init_vec<T>(it: impl Iterator<Item=T>) -> Option<Vec<T>> {
+    init(it).map(Iterator::collect)
+}
+
+/// Skip first n elements lazily
+///
+/// # Arguments
+///
+/// * `n`: elements count to skip
+/// * `it`: [`Iterator`] T
+pub fn skip<I: Iterator>(n: usize, it: I) -> Skip<I> {
+    it.skip(n)
 }
 
 /// Skip first n elements and return a new [`Vec`].
@@ -129,13 +313,19 @@ pub fn init<T>(it: impl Iterator<Item=T>) -> Option<Vec<T>> {
 ///
 /// * `n`: elements count to skip
 /// * `it`: [`Iterator`] T
-pub fn skip<T>(n: usize, mut it: impl Iterator<Item=T>) -> Vec<T> {
-    for _ in 0..n {
-        it.next();
-    }
-    let mut ret = Vec::new();
-    ret.extend(it);
-    ret
+#[cfg(feature = "alloc")]
+pub fn skip_vec<T>(n: usize, it: impl Iterator<Item=T>) -> Vec<T> {
+    skip(n, it).collect()
+}
+
+/// Take first n elements lazily
+///
+/// # Arguments
+///
+/// * `n`: elements count to take
+/// * `it`: [`Iterator`] T
+pub fn take<I: Iterator>(n: usize, it: I) -> Take<I> {
+    it.take(n)
 }
 
 /// Take first n elements and return a new [`Vec`].
@@ -144,15 +334,9 @@ pub fn skip<T>(n: usize, mut it: impl Iterator<Item=T>) -> Vec<T> {
 ///
 /// * `n`: elements count to take
 /// * `it`: [`Iterator`] T
-pub fn take<T>(n: usize, mut it: impl Iterator<Item=T>) -> Vec<T> {
-    let mut ret = Vec::new();
-    for _ in 0..n {
-        match it.next() {
-            None => break,
-            Some(item) => ret.push(item)
-        }
-    }
-    ret
+#[cfg(feature = "alloc")]
+pub fn take_vec<T>(n: usize, it: impl Iterator<Item=T>) -> Vec<T> {
+    take(n, it).collect()
 }
 
 /// Invoke [`Mul`] over a [`Iterator`]<T>
@@ -173,28 +357,46 @@ pub fn length<T>(it: impl Iterator<Item=T>) -> usize {
     it.count()
 }
 
-/// Reverse a [`Iterator`]<T>
+/// Reverse a [`DoubleEndedIterator`]<T> lazily
+///
+/// # Arguments
+///
+/// * `it`: [`DoubleEndedIterator`] T
+pub fn reverse<I: DoubleEndedIterator>(it: I) -> Rev<I> {
+    it.rev()
+}
+
+/// Reverse a [`Iterator`]<T> into a new [`Vec`]<T>, works for single-ended [`Iterator`]
 ///
 /// # Arguments
 ///
 /// * `it`: [`Iterator`] T
-pub fn reverse<T>(it: impl Iterator<Item=T>) -> Vec<T> {
-    let mut ret = Vec::new();
-    ret.extend(it);
+#[cfg(feature = "alloc")]
+pub fn reverse_vec<T>(it: impl Iterator<Item=T>) -> Vec<T> {
+    let mut ret: Vec<T> = it.collect();
     ret.reverse();
     ret
 }
 
+/// Concat two [`Iterator`]<T> into one lazily
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+pub fn concat<I: Iterator, J: Iterator<Item=I::Item>>(it1: I, it2: J) -> Chain<I, J> {
+    it1.chain(it2)
+}
+
 /// Concat two [`Iterator`]<T> into one [`Vec`]<T>
 ///
 /// # Arguments
 ///
-/// * `it`: [`Iterator`] T
-pub fn concat<T>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> Vec<T> {
-    let mut ret = Vec::new();
-    ret.extend(it1);
-    ret.extend(it2);
-    ret
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn concat_vec<T>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> Vec<T> {
+    concat(it1, it2).collect()
 }
 
 /// Return what you pass to this function
@@ -294,6 +496,7 @@ pub fn find<K:Hash+Eq,V>(key: K, mut it: impl Iterator<Item=(K,V)>) -> Option<(K
 ///
 /// # Arguments
 /// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
 pub fn sorted<T: Ord>(it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
     let mut tmp = Vec::new();
     tmp.extend(it);
@@ -305,7 +508,8 @@ pub fn sorted<T: Ord>(it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
 ///
 /// # Arguments
 /// * `f`: (&T -> &T) -> [`Ordering`]
-/// *`it`: [`Iterator`] T
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
 pub fn sorted_by<T>(f: impl Fn(&T,&T) -> Ordering, it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
     let mut tmp = Vec::new();
     tmp.extend(it);
@@ -313,17 +517,13 @@ pub fn sorted_by<T>(f: impl Fn(&T,&T) -> Ordering, it: impl Iterator<Item=T>) ->
     tmp.into_iter()
 }
 
-/// [`Zip`] two iterator into one iterator which return same index of item on two iterators
+/// [`Zip`] two iterator into one iterator which return same index of item on two iterators lazily
 ///
 /// # Arguments
 /// * `it1`: [`Iterator`] T
-/// * `it2`: [`Iterator`] T
-pub fn zip<T,U>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=U>) -> Zip<impl Iterator<Item=T>, impl Iterator<Item=U>> {
-    let mut ret1 = Vec::new();
-    ret1.extend(it1);
-    let mut ret2 = Vec::new();
-    ret2.extend(it2);
-    ret1.into_iter().zip(ret2.into_iter())
+/// * `it2`: [`Iterator`] U
+pub fn zip<I: Iterator, J: Iterator>(it1: I, it2: J) -> Zip<I, J> {
+    it1.zip(it2)
 }
 
 /// [`Zip`] two iterator into one iterator by mapping with a function
@@ -332,6 +532,295 @@ pub fn zip<T,U>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=U>) -> Zip<i
 /// * `f`: Fn (T,U) -> V
 /// * `it1`: [`Iterator`] T
 /// * `it2`: [`Iterator`] U
-pub fn zip_with<T,U,V>(f: impl Fn((T,U)) -> V, it1: impl Iterator<Item=T>, it2: impl Iterator<Item=U>) -> impl Iterator<Item=V> {
+pub fn zip_with<I: Iterator, J: Iterator, V, F: Fn((I::Item, J::Item)) -> V>(f: F, it1: I, it2: J) -> Map<Zip<I, J>, F> {
     zip(it1,it2).map(f)
-}
\ No newline at end of file
+}
+
+/// Flip the order of the two arguments of a function
+///
+/// # Arguments
+///
+/// * `f`: f :: (A, B) -> R
+///
+/// **Signature**: ((A, B) -> R) -> (B, A) -> R
+pub fn flip<A, B, R>(f: impl Fn(A, B) -> R) -> impl Fn(B, A) -> R {
+    move |b, a| f(a, b)
+}
+
+/// Group elements by key into a [`HashMap`], elements of a group keep their order
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> K, key of element
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn group_by<T, K: Hash + Eq>(f: impl Fn(&T) -> K, it: impl Iterator<Item=T>) -> HashMap<K, Vec<T>> {
+    let mut ret = HashMap::new();
+    for x in it {
+        ret.entry(f(&x)).or_insert_with(Vec::new).push(x);
+    }
+    ret
+}
+
+/// Group elements by key into a [`BTreeMap`] sorted by key, elements of a group keep their order
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> K, key of element
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn group_by_ord<T, K: Ord>(f: impl Fn(&T) -> K, it: impl Iterator<Item=T>) -> BTreeMap<K, Vec<T>> {
+    let mut ret = BTreeMap::new();
+    for x in it {
+        ret.entry(f(&x)).or_insert_with(Vec::new).push(x);
+    }
+    ret
+}
+
+/// Group runs of consecutive elements lazily, next element joins the run if `f(last, next)` is true
+///
+/// # Arguments
+///
+/// * `f`: f :: (&T, &T) -> [`bool`]
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn group_with<I: Iterator, F: Fn(&I::Item, &I::Item) -> bool>(f: F, it: I) -> GroupWith<I, F> {
+    GroupWith::new(f, it)
+}
+
+/// Split elements into two collections, which satisfy `f` and which do not
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> [`bool`]
+/// * `it`: [`Iterator`] T
+pub fn partition<T, B: Default + Extend<T>>(f: impl Fn(&T) -> bool, it: impl Iterator<Item=T>) -> (B, B) {
+    it.partition(f)
+}
+
+/// Split at index n, first n elements are collected and the rest is left lazy
+///
+/// # Arguments
+///
+/// * `n`: count of elements of first part
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn split_at<I: Iterator>(n: usize, mut it: I) -> (Vec<I::Item>, I) {
+    let first = it.by_ref().take(n).collect();
+    (first, it)
+}
+
+/// Split into chunks with n elements lazily, the last chunk may be shorter. Panics if n is 0.
+///
+/// # Arguments
+///
+/// * `n`: count of elements of a chunk
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn split_every<I: Iterator>(n: usize, it: I) -> SplitEvery<I> {
+    SplitEvery::new(n, it)
+}
+
+/// Split before the first element satisfying `f`, elements before it are collected and the rest is left lazy
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> [`bool`]
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn split_when<I: Iterator>(f: impl Fn(&I::Item) -> bool, mut it: I) -> (Vec<I::Item>, SplitWhen<I>) {
+    let mut first = Vec::new();
+    let mut rest = None;
+    for x in it.by_ref() {
+        if f(&x) {
+            rest = Some(x);
+            break;
+        }
+        first.push(x);
+    }
+    (first, rest.into_iter().chain(it))
+}
+
+/// Remove duplicated elements lazily, the first one is kept and the order is preserved
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn unique<T: Hash + Eq + Clone>(it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    unique_by(T::clone, it)
+}
+
+/// [unique] for [`Ord`] elements
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn unique_ord<T: Ord + Clone>(it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let mut seen = BTreeSet::new();
+    it.filter(move |x| seen.insert(x.clone()))
+}
+
+/// Remove elements with duplicated key lazily, the first one is kept and the order is preserved
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> K, key of element
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn unique_by<T, K: Hash + Eq>(f: impl Fn(&T) -> K, it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let mut seen = HashSet::new();
+    it.filter(move |x| seen.insert(f(x)))
+}
+
+/// Remove duplicated elements by a comparator, the first one is kept and the order is preserved. O(n^2)
+///
+/// # Arguments
+///
+/// * `eq`: eq :: (&T, &T) -> [`bool`]
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn unique_with<T>(eq: impl Fn(&T, &T) -> bool, it: impl Iterator<Item=T>) -> Vec<T> {
+    let mut ret: Vec<T> = Vec::new();
+    for x in it {
+        if !ret.iter().any(|y| eq(y, &x)) {
+            ret.push(x);
+        }
+    }
+    ret
+}
+
+/// Elements in either [`Iterator`] without duplication lazily, in order of appearance
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn union<T: Hash + Eq + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    unique(it1.chain(it2))
+}
+
+/// [union] for [`Ord`] elements
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn union_ord<T: Ord + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    unique_ord(it1.chain(it2))
+}
+
+/// [union] by a comparator. O(n^2)
+///
+/// # Arguments
+///
+/// * `eq`: eq :: (&T, &T) -> [`bool`]
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn union_with<T>(eq: impl Fn(&T, &T) -> bool, it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> Vec<T> {
+    unique_with(eq, it1.chain(it2))
+}
+
+/// Elements of `it1` which are also in `it2` without duplication, in order of `it1`.
+/// `it2` is collected at once and `it1` is filtered lazily.
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn intersection<T: Hash + Eq + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let other: HashSet<T> = it2.collect();
+    unique(it1.filter(move |x| other.contains(x)))
+}
+
+/// [intersection] for [`Ord`] elements
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn intersection_ord<T: Ord + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let other: BTreeSet<T> = it2.collect();
+    unique_ord(it1.filter(move |x| other.contains(x)))
+}
+
+/// [intersection] by a comparator. O(n^2)
+///
+/// # Arguments
+///
+/// * `eq`: eq :: (&T, &T) -> [`bool`]
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn intersection_with<T>(eq: impl Fn(&T, &T) -> bool, it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> Vec<T> {
+    let other: Vec<T> = it2.collect();
+    unique_with(&eq, it1.filter(|x| other.iter().any(|y| eq(x, y))))
+}
+
+/// Elements of `it1` which are not in `it2` without duplication, in order of `it1`.
+/// `it2` is collected at once and `it1` is filtered lazily.
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn diff<T: Hash + Eq + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let other: HashSet<T> = it2.collect();
+    unique(it1.filter(move |x| !other.contains(x)))
+}
+
+/// [diff] for [`Ord`] elements
+///
+/// # Arguments
+///
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn diff_ord<T: Ord + Clone>(it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let other: BTreeSet<T> = it2.collect();
+    unique_ord(it1.filter(move |x| !other.contains(x)))
+}
+
+/// [diff] by a comparator. O(n^2)
+///
+/// # Arguments
+///
+/// * `eq`: eq :: (&T, &T) -> [`bool`]
+/// * `it1`: [`Iterator`] T
+/// * `it2`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn diff_with<T>(eq: impl Fn(&T, &T) -> bool, it1: impl Iterator<Item=T>, it2: impl Iterator<Item=T>) -> Vec<T> {
+    let other: Vec<T> = it2.collect();
+    unique_with(&eq, it1.filter(|x| !other.iter().any(|y| eq(x, y))))
+}
+
+/// Remove all elements which are in `xs` lazily, duplications of other elements are kept
+///
+/// # Arguments
+///
+/// * `xs`: [`Iterator`] T, elements to remove
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "std")]
+pub fn without<T: Hash + Eq>(xs: impl Iterator<Item=T>, it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let removed: HashSet<T> = xs.collect();
+    it.filter(move |x| !removed.contains(x))
+}
+
+/// [without] for [`Ord`] elements
+///
+/// # Arguments
+///
+/// * `xs`: [`Iterator`] T, elements to remove
+/// * `it`: [`Iterator`] T
+#[cfg(feature = "alloc")]
+pub fn without_ord<T: Ord>(xs: impl Iterator<Item=T>, it: impl Iterator<Item=T>) -> impl Iterator<Item=T> {
+    let removed: BTreeSet<T> = xs.collect();
+    it.filter(move |x| !removed.contains(x))
+}
@@ -10,7 +10,7 @@
 /// **Signature**: map :: (T -> U) -> [`Iterator`] T -> [`Iterator`] U
 #[macro_export] macro_rules! map {
     ($f:expr) => {
-        move |it| map($f, it)
+        move |it| $crate::basic_fn::fun::map($f, it)
     };
 }
 
@@ -21,10 +21,10 @@
 /// 2. sum!(0,1,2,3,4,5)
 #[macro_export] macro_rules! sum {
     ($i:expr;$j:expr) => {{
-        sum($i..=$j)
+        $crate::basic_fn::fun::sum($i..=$j)
     }};
     ($i:expr,$($j:expr),*) => {
-        $i + sum!($($j),*)
+        $i + $crate::sum!($($j),*)
     };
     ($i:expr) => {
         $i
@@ -39,11 +39,11 @@
 /// * `args` - function or closure separated by comma(,)
 #[macro_export] macro_rules! pipe  {
     ($($f:expr),*) => {
-        |v| {pipe!(@NEXT v, $($f),*) }
+        |v| {$crate::pipe!(@NEXT v, $($f),*) }
     };
 
     (@NEXT $v:expr,$f:expr,$($rest:expr),*) => {
-        pipe!(@NEXT $f($v), $($rest),*)
+        $crate::pipe!(@NEXT $f($v), $($rest),*)
     };
 
     (@NEXT $v:expr,$last:expr) => {
@@ -52,44 +52,138 @@
 }
 
 /// This macro is used to provide ability of list comprehension.
-/// Return [`Vec`]<T>.
-/// 
-/// Format:
-/// `ls![{Mapper};{Iter}=>{Filterer}]`
+/// Return [`Vec`]<T>, or a lazy [`Iterator`]<T> if it starts with `iter`.
+///
+/// Comprehension format, clauses are separated by comma(,) and run from left to right:
+/// `ls![{Expr}; {Clause}, {Clause}...]`
+/// `ls![iter {Expr}; {Clause}, {Clause}...]`
+///
+/// Clause:
+/// 1. `{Pattern} <- {Iter}`, generator, a later generator is nested in an earlier one.
+///    Items not matching the pattern are skipped
+/// 2. `let {Pattern} = {Expr}`, binding
+/// 3. `{Expr}`, guard, item is kept only if it is `true`.
+///    Wrap negative number by parentheses, e.g. `x < (-1)`, as `<-` is taken as generator
 ///
 /// Haskell form:
 /// ```haskell
-/// [{Mapper}(x) | x <- {Iter}, {Filterer}(x)]
+/// [(x, y) | x <- [1..3], y <- "ab", odd x]
 /// ```
+/// Rust form:
+/// ```ignore
+/// ls![(x, y); x <- 1..4, y <- "ab".chars(), x % 2 == 1]
+/// ```
+/// In lazy mode every generator is a `move` closure, so borrow or clone non-[`Copy`] values used by inner generators.
+///
+/// Shorthand format:
+/// `ls![{Mapper};{Iter}=>{Filterer}]`
+///
 /// Python form:
 /// ```python
 /// [{Mapper}(x) for x in {Iter} if {Filterer}(x)]
 /// ```
-/// 
+///
 /// # Arguments
-/// 
+///
 /// * `Mapper`: T -> U - Optional, function to map on item
 /// * `Iter` - [`Iterator`]<T>
-/// * `Filterer` T -> bool - Optional, to filter items
+/// * `Filterer` &T -> bool - Optional, to filter items
 #[macro_export] macro_rules! ls {
-    ($it:expr) => {
-        ls![|x| x;$it=>|_|true]
+    (@VEC $ret:ident, $head:expr; ) => {
+        $ret.push($head);
     };
-    ($mapper:expr;$it:expr) => {
-        ls![$mapper;$it=>|_| true]
+    (@VEC $ret:ident, $head:expr; , $($rest:tt)*) => {
+        $crate::ls!(@VEC $ret, $head; $($rest)*)
     };
-    ($it:expr=>$filterer:expr) => {
-        ls![|x| x;$it=>$filterer]
+    (@VEC $ret:ident, $head:expr; let $p:pat = $e:expr, $($rest:tt)*) => {{
+        let $p = $e;
+        $crate::ls!(@VEC $ret, $head; $($rest)*)
+    }};
+    (@VEC $ret:ident, $head:expr; $($rest:tt)+) => {
+        $crate::ls!(@VEC_CLAUSE $ret, $head; []; $($rest)+)
     };
-    ($mapper:expr;$it:expr=>$filterer:expr) => {{
-        let mut ret = Vec::new();
+    (@VEC_CLAUSE $ret:ident, $head:expr; [$($p:tt)+]; <- $e:expr, $($rest:tt)*) => {
+        for item in $e {
+            match item {
+                $($p)+ => { $crate::ls!(@VEC $ret, $head; $($rest)*) }
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        }
+    };
+    (@VEC_CLAUSE $ret:ident, $head:expr; [$($g:tt)+]; , $($rest:tt)*) => {
+        if $($g)+ { $crate::ls!(@VEC $ret, $head; $($rest)*) }
+    };
+    (@VEC_CLAUSE $ret:ident, $head:expr; [$($p:tt)*]; $t:tt $($rest:tt)*) => {
+        $crate::ls!(@VEC_CLAUSE $ret, $head; [$($p)* $t]; $($rest)*)
+    };
+
+    (@ITER $head:expr; ) => {
+        $crate::__private::iter::once($head)
+    };
+    (@ITER $head:expr; , $($rest:tt)*) => {
+        $crate::ls!(@ITER $head; $($rest)*)
+    };
+    (@ITER $head:expr; let $p:pat = $e:expr, $($rest:tt)*) => {{
+        let $p = $e;
+        $crate::ls!(@ITER $head; $($rest)*)
+    }};
+    (@ITER $head:expr; $($rest:tt)+) => {
+        $crate::ls!(@ITER_CLAUSE $head; []; $($rest)+)
+    };
+    (@ITER_CLAUSE $head:expr; [$($p:tt)+]; <- $e:expr, $($rest:tt)*) => {
+        $crate::__private::iter::IntoIterator::into_iter($e).flat_map(move |item| {
+            (match item {
+                $($p)+ => Some($crate::ls!(@ITER $head; $($rest)*)),
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }).into_iter().flatten()
+        })
+    };
+    (@ITER_CLAUSE $head:expr; [$($g:tt)+]; , $($rest:tt)*) => {
+        (if $($g)+ { Some($crate::ls!(@ITER $head; $($rest)*)) } else { None }).into_iter().flatten()
+    };
+    (@ITER_CLAUSE $head:expr; [$($p:tt)*]; $t:tt $($rest:tt)*) => {
+        $crate::ls!(@ITER_CLAUSE $head; [$($p)* $t]; $($rest)*)
+    };
+
+    // comprehension has at least one generator, otherwise it is the shorthand format
+    (@DETECT [$head:expr] [$($all:tt)*] <- $($t:tt)*) => {{
+        let mut ret = $crate::__private::vec::Vec::new();
+        $crate::ls!(@VEC ret, $head; $($all)*,);
+        ret
+    }};
+    (@DETECT [$head:expr] [$($all:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::ls!(@DETECT [$head] [$($all)*] $($rest)*)
+    };
+    (@DETECT [$mapper:expr] [$($all:tt)*]) => {
+        $crate::ls!(@MAP $mapper; $($all)*)
+    };
+    (@MAP $mapper:expr; $it:expr) => {
+        $crate::ls!(@MAP $mapper; $it=>|_| true)
+    };
+    (@MAP $mapper:expr; $it:expr=>$filterer:expr) => {{
+        let mut ret = $crate::__private::vec::Vec::new();
         for i in $it {
-            if $filterer(i) {
+            if $filterer(&i) {
                 ret.push($mapper(i));
             }
         }
         ret
     }};
+
+    (iter $head:expr; $($clauses:tt)+) => {
+        $crate::ls!(@ITER $head; $($clauses)+,)
+    };
+    ($head:expr; $($rest:tt)+) => {
+        $crate::ls!(@DETECT [$head] [$($rest)+] $($rest)+)
+    };
+    ($it:expr) => {
+        $crate::ls![|x| x;$it=>|_|true]
+    };
+    ($it:expr=>$filterer:expr) => {
+        $crate::ls![|x| x;$it=>$filterer]
+    };
 }
 
 /// Curry macro of [foldl](basic_fn::fun::foldl)
@@ -97,13 +191,13 @@
 /// **Signature**: foldl :: R -> (R -> T -> R) -> [`DoubleEndedIterator`] T -> R
 #[macro_export] macro_rules! foldl {
     ($init:expr,$f:expr) => {
-        move |it| foldl($init,$f,it)
+        move |it| $crate::basic_fn::fun::foldl($init,$f,it)
     };
     ($init:expr) => {
-        move |f,it| foldl($init,f,it)
+        move |f,it| $crate::basic_fn::fun::foldl($init,f,it)
     };
     ($init:expr=>) => {
-        move |f| (move |it| foldl($init,f,it))
+        move |f| (move |it| $crate::basic_fn::fun::foldl($init,f,it))
     };
 }
 
@@ -112,13 +206,115 @@
 /// **Signature**: foldr :: R -> (R -> T -> R) -> [`DoubleEndedIterator`] T -> R
 #[macro_export] macro_rules! foldr {
     ($init:expr,$f:expr) => {
-        move |it| foldr($init,$f,it)
+        move |it| $crate::basic_fn::fun::foldr($init,$f,it)
+    };
+    ($init:expr) => {
+        move |f,it| $crate::basic_fn::fun::foldr($init,f,it)
+    };
+    ($init:expr=>) => {
+        move |f| (move |it| $crate::basic_fn::fun::foldr($init,f,it))
+    };
+}
+
+/// Curry macro of [fold_while](basic_fn::fun::fold_while)
+/// 
+/// **Signature**: fold_while :: R -> (R -> T -> [`ControlFlow`](std::ops::ControlFlow) R R) -> [`Iterator`] T -> R
+#[macro_export] macro_rules! fold_while {
+    ($init:expr,$f:expr) => {
+        move |it| $crate::basic_fn::fun::fold_while($init,$f,it)
+    };
+    ($init:expr) => {
+        move |f,it| $crate::basic_fn::fun::fold_while($init,f,it)
+    };
+    ($init:expr=>) => {
+        move |f| (move |it| $crate::basic_fn::fun::fold_while($init,f,it))
+    };
+}
+
+/// Curry macro of [foldl_by](basic_fn::fun::foldl_by)
+/// 
+/// **Signature**: foldl_by :: R -> (R -> T -> R) -> (&T -> K) -> [`Iterator`] T -> [`HashMap`](std::collections::HashMap) K R
+#[macro_export] macro_rules! foldl_by {
+    ($init:expr,$f:expr,$key:expr) => {
+        move |it| $crate::basic_fn::fun::foldl_by($init,$f,$key,it)
+    };
+    ($init:expr,$f:expr) => {
+        move |key,it| $crate::basic_fn::fun::foldl_by($init,$f,key,it)
     };
     ($init:expr) => {
-        move |f,it| foldr($init,f,it)
+        move |f,key,it| $crate::basic_fn::fun::foldl_by($init,f,key,it)
+    };
+}
+
+/// Curry macro of [foldr_by](basic_fn::fun::foldr_by)
+/// 
+/// **Signature**: foldr_by :: R -> (R -> T -> R) -> (&T -> K) -> [`DoubleEndedIterator`] T -> [`HashMap`](std::collections::HashMap) K R
+#[macro_export] macro_rules! foldr_by {
+    ($init:expr,$f:expr,$key:expr) => {
+        move |it| $crate::basic_fn::fun::foldr_by($init,$f,$key,it)
+    };
+    ($init:expr,$f:expr) => {
+        move |key,it| $crate::basic_fn::fun::foldr_by($init,$f,key,it)
+    };
+    ($init:expr) => {
+        move |f,key,it| $crate::basic_fn::fun::foldr_by($init,f,key,it)
+    };
+}
+
+/// Curry macro of [scan](basic_fn::fun::scan)
+/// 
+/// **Signature**: scan :: R -> (R -> T -> R) -> [`Iterator`] T -> [`Iterator`] R
+#[macro_export] macro_rules! scan {
+    ($init:expr,$f:expr) => {
+        move |it| $crate::basic_fn::fun::scan($init,$f,it)
+    };
+    ($init:expr) => {
+        move |f,it| $crate::basic_fn::fun::scan($init,f,it)
+    };
+    ($init:expr=>) => {
+        move |f| (move |it| $crate::basic_fn::fun::scan($init,f,it))
+    };
+}
+
+/// Curry macro of [map_accum](basic_fn::fun::map_accum)
+/// 
+/// **Signature**: map_accum :: S -> (S -> T -> (S, U)) -> [`Iterator`] T -> (S, [`Vec`] U)
+#[macro_export] macro_rules! map_accum {
+    ($init:expr,$f:expr) => {
+        move |it| $crate::basic_fn::fun::map_accum($init,$f,it)
+    };
+    ($init:expr) => {
+        move |f,it| $crate::basic_fn::fun::map_accum($init,f,it)
     };
     ($init:expr=>) => {
-        move |f| (move |it| foldr($init,f,it))
+        move |f| (move |it| $crate::basic_fn::fun::map_accum($init,f,it))
+    };
+}
+
+/// Curry macro of [map_accum_right](basic_fn::fun::map_accum_right)
+/// 
+/// **Signature**: map_accum_right :: S -> (S -> T -> (S, U)) -> [`DoubleEndedIterator`] T -> (S, [`Vec`] U)
+#[macro_export] macro_rules! map_accum_right {
+    ($init:expr,$f:expr) => {
+        move |it| $crate::basic_fn::fun::map_accum_right($init,$f,it)
+    };
+    ($init:expr) => {
+        move |f,it| $crate::basic_fn::fun::map_accum_right($init,f,it)
+    };
+    ($init:expr=>) => {
+        move |f| (move |it| $crate::basic_fn::fun::map_accum_right($init,f,it))
+    };
+}
+
+/// Curry macro of [unfold](basic_fn::fun::unfold)
+/// 
+/// **Signature**: unfold :: S -> (S -> [`Option`] (T, S)) -> [`Iterator`] T
+#[macro_export] macro_rules! unfold {
+    ($seed:expr,$f:expr) => {
+        $crate::basic_fn::fun::unfold($seed,$f)
+    };
+    ($seed:expr) => {
+        move |f| $crate::basic_fn::fun::unfold($seed,f)
     };
 }
 
@@ -127,7 +323,7 @@
 /// **Signature**: filter :: (T -> bool) -> [`Iterator`] T -> [`Iterator`] T
 #[macro_export] macro_rules! filter {
     ($f:expr) => {
-        move |it| filter($f, it)
+        move |it| $crate::basic_fn::fun::filter($f, it)
     };
 }
 
@@ -136,7 +332,7 @@
 /// **Signature**: filter_not :: (T -> bool) -> [`Iterator`]) T -> [`Iterator`] T
 #[macro_export] macro_rules! filter_not {
     ($f:expr) => {
-        move |it| filter_not($f, it)
+        move |it| $crate::basic_fn::fun::filter_not($f, it)
     };
 }
 
@@ -148,13 +344,13 @@
 /// * `args` - function or closure separated by comma(,)
 #[macro_export] macro_rules! compose {
     ($f:expr,$($fs:expr),*) => {
-        compose!(@NEXT [$f],$($fs),*)
+        $crate::compose!(@NEXT [$f],$($fs),*)
     };
     (@NEXT [$($fs:expr),*],$f:expr,$($remain_fs:expr),*) => {
-        compose!(@NEXT [$f,$($fs),*],$($remain_fs),*)
+        $crate::compose!(@NEXT [$f,$($fs),*],$($remain_fs),*)
     };
     (@NEXT [$($fs:expr),*],$f:expr) => {
-        pipe!($f,$($fs),*)
+        $crate::pipe!($f,$($fs),*)
     };
 }
 
@@ -163,7 +359,7 @@
 /// **Signature**: skip :: [`usize`] -> [`Iterator`] T -> [`Iterator`] T
 #[macro_export] macro_rules! skip {
     ($n:expr) => {
-        move |it| skip($n, it)
+        move |it| $crate::basic_fn::fun::skip($n, it)
     };
 }
 
@@ -172,7 +368,7 @@
 /// **Signature**: take :: [`usize`] -> [`Iterator`] T -> [`Iterator`] T
 #[macro_export] macro_rules! take {
     ($n:expr) => {
-        move |it| take($n, it)
+        move |it| $crate::basic_fn::fun::take($n, it)
     };
 }
 
@@ -183,24 +379,25 @@
 /// 2. product!(0,1,2,3,4,5)
 #[macro_export] macro_rules! product {
     ($i:expr;$j:expr) => {{
-        product($i..=$j)
+        $crate::basic_fn::fun::product($i..=$j)
     }};
     ($i:expr,$($j:expr),*) => {{
-        $i * product!($($j),*)
+        $i * $crate::product!($($j),*)
     }};
     ($i:expr) => {
         $i
     };
 }
 
-/// Extend [concat](basic_fn::fun::concat)
+/// Extend [concat_vec](basic_fn::fun::concat_vec) to any count of [`Iterator`],
+/// not named `concat!` to keep std `concat!` usable
 ///
 /// Syntax:
-/// concat!(it1;it2;it3...)
-#[macro_export] macro_rules! concat {
+/// concat_vec!(it1;it2;it3...)
+#[macro_export] macro_rules! concat_vec {
     ($($it:expr);*) => {{
-        let mut ret = Vec::new();
-        $(ret.extend($it););*
+        let mut ret = $crate::__private::vec::Vec::new();
+        $(ret.extend($it);)*
         ret
     }};
 }
@@ -231,61 +428,58 @@
 
 /// Get reminder of division
 #[macro_export] macro_rules! rem {
-    ($x:expr,$y:expr) => {{rem($x, $y)}};
-    ($x:expr) => {move |y| rem($x, y)};
+    ($x:expr,$y:expr) => {{$crate::basic_fn::fun::rem($x, $y)}};
+    ($x:expr) => {move |y| $crate::basic_fn::fun::rem($x, y)};
 }
 
-/// Absolute of signed
+/// Absolute of signed, macro of [abs](num::abs)
 ///
 /// Syntax:
 /// 1. abs(>type) :: Signed type => type -> type
 /// 2. abs(x) -> x
 #[macro_export] macro_rules! abs {
-    (>$t:ty) => {move |x: $t| x.abs()};
-    ($x:expr) => {{$x.abs()}};
+    (>$t:ty) => {move |x: $t| $crate::num::abs(x)};
+    ($x:expr) => {{$crate::num::abs($x)}};
 }
 
-/// Signum of signed
+/// Signum of signed, macro of [signum](num::signum)
 ///
 /// Syntax:
 /// 1. signum(>type) :: Signed type => type -> -1|0|1
 /// 2. signum(x) -> -1|0|1
 #[macro_export] macro_rules! signum {
-    (>$t:ty) => {move |x: $t| x.signum()};
-    ($x:expr) => {{$x.signum()}};
+    (>$t:ty) => {move |x: $t| $crate::num::signum(x)};
+    ($x:expr) => {{$crate::num::signum($x)}};
 }
 
-/// check if a number is even
+/// check if a number is even, macro of [even](num::even)
 ///
 /// Syntax:
 /// 1. even!(x) -> bool
-/// 2. even!(>type) :: Integer -> bool
+/// 2. even!(>type) :: Integral type => type -> bool
 #[macro_export] macro_rules! even {
-    (>$t:ty) => {move |x: $t| x % 2 == 0};
-    ($x:expr) => {{$x % 2 == 0}};
+    (>$t:ty) => {move |x: $t| $crate::num::even(x)};
+    ($x:expr) => {{$crate::num::even($x)}};
 }
 
-/// check if a number is odd
+/// check if a number is odd, macro of [odd](num::odd)
 ///
 /// Syntax:
 /// 1. odd!(x) -> bool
-/// 2. odd!(>type) :: Integer -> bool
+/// 2. odd!(>type) :: Integral type => type -> bool
 #[macro_export] macro_rules! odd {
-    (>$t:ty) => {move |x: $t| x % 2 == 1};
-    ($x:expr) => {{$x % 2 == 1}};
+    (>$t:ty) => {move |x: $t| $crate::num::odd(x)};
+    ($x:expr) => {{$crate::num::odd($x)}};
 }
 
-/// 1/x
+/// 1/x, macro of [recip](num::recip)
 ///
 /// Syntax:
-/// 1. recip!(>type) :: T -> T
+/// 1. recip!(>type) :: Fractional type => type -> type
 /// 2. recip!(x) = 1/x
-///
-/// # Panics
-/// if x == 0
 #[macro_export] macro_rules! recip {
-    (>$t:ty) => {move |x: $t| 1/$t};
-    ($x:expr) => {{1/$x}};
+    (>$t:ty) => {move |x: $t| $crate::num::recip(x)};
+    ($x:expr) => {{$crate::num::recip($x)}};
 }
 
 /// always return first memorized values
@@ -348,8 +542,8 @@
 /// 1. find!(k) :: [`Iterator`] (K,V) -> Option (K,V)
 /// 2. find!(k,iter) = find(k,iter)
 #[macro_export] macro_rules! find {
-    ($k:expr) => {move |it| find($k,it)};
-    ($k:expr,$it:expr) => {{find($k,$it)}};
+    ($k:expr) => {move |it| $crate::basic_fn::fun::find($k,it)};
+    ($k:expr,$it:expr) => {{$crate::basic_fn::fun::find($k,$it)}};
 }
 
 /// macro of [sorted_by](basic_fn::fun::sorted_by)
@@ -357,16 +551,16 @@
 /// Syntax:
 /// sorted_by!(f) :: [`Iterator`] T -> [`Iterator`] T
 #[macro_export] macro_rules! sorted_by {
-    ($f:expr) => {move |it| sorted_by($f, it)};
+    ($f:expr) => {move |it| $crate::basic_fn::fun::sorted_by($f, it)};
 }
 
-/// macro of sorted_with
-/// 
-/// fs = [f], f :: (&T, &T) -> Ordering
-/// 
-/// Syntax:
-/// 1. sorted_with!(fs,...) :: [`Iterator`] T -> [`Iterator`] T
-/// 2. sorted_with!(fs,...;it) = [`Iterator`] T
+// macro of sorted_with
+// 
+// fs = [f], f :: (&T, &T) -> Ordering
+// 
+// Syntax:
+// 1. sorted_with!(fs,...) :: [`Iterator`] T -> [`Iterator`] T
+// 2. sorted_with!(fs,...;it) = [`Iterator`] T
 // TODO - to be continued
 // #[macro_export] macro_rules! sorted_with {
 //     ($($f:expr),*) => {move |it| sorted_with!($($f),*;it)};
@@ -388,7 +582,7 @@
 /// Syntax:
 /// zip!(it) :: [`Iterator`] U -> [`Iterator`] (T,U)
 #[macro_export] macro_rules! zip {
-    ($it:expr) => {move |it| zip($it, it)};
+    ($it:expr) => {move |it| $crate::basic_fn::fun::zip($it, it)};
 }
 
 /// macro of [zip_with](basic_fn::fun::zip_with)
@@ -398,7 +592,343 @@
 /// 2. zip_with!(f=>) :: [`Iterator`] T -> [`Iterator`] U -> [`Iterator`] V
 /// 3. zip_with!(f, it) :: [`Iterator`] U -> [`Iterator`] V
 #[macro_export] macro_rules! zip_with {
-    ($f:expr) => {move |it1, it2| zip_with($f, it1, it2)};
-    ($f:expr=>) => {move |it1| (move |it2| zip_with($f, it1, it2))};
-    ($f:expr,$it:expr) => {move |it| zip_with($f, $it, it)};
-}
\ No newline at end of file
+    ($f:expr) => {move |it1, it2| $crate::basic_fn::fun::zip_with($f, it1, it2)};
+    ($f:expr=>) => {move |it1| (move |it2| $crate::basic_fn::fun::zip_with($f, it1, it2))};
+    ($f:expr,$it:expr) => {move |it| $crate::basic_fn::fun::zip_with($f, $it, it)};
+}
+
+/// Partial application at any position, `_` is the placeholder of argument to be applied later.
+/// Supplied arguments are evaluated once and cloned into every call, so they must be [`Clone`].
+///
+/// Syntax:
+/// 1. partial!(rem, _, 3) :: T -> T, equals `move |x| rem(x, 3)`
+/// 2. partial!(foldl, 0, _, _) :: (R -> T -> R) -> [`DoubleEndedIterator`] T -> R
+/// 3. partial!(f, x, y) :: () -> R
+#[macro_export] macro_rules! partial {
+    (@NEXT $f:expr; [$($p:ident)*]; [$($b:ident = $v:expr),*]; [$($a:expr),*]; _, $($rest:tt)*) => {
+        $crate::partial!(@NEXT $f; [$($p)* x]; [$($b = $v),*]; [$($a,)* x]; $($rest)*)
+    };
+    (@NEXT $f:expr; [$($p:ident)*]; [$($b:ident = $v:expr),*]; [$($a:expr),*]; $x:expr, $($rest:tt)*) => {
+        $crate::partial!(@NEXT $f; [$($p)*]; [$($b = $v,)* a = $x]; [$($a,)* $crate::__private::clone::Clone::clone(&a)]; $($rest)*)
+    };
+    (@NEXT $f:expr; [$($p:ident)*]; [$($b:ident = $v:expr),*]; [$($a:expr),*]; $(,)*) => {{
+        $(let $b = $v;)*
+        move |$($p),*| $f($($a),*)
+    }};
+    ($f:expr, $($args:tt)*) => {
+        $crate::partial!(@NEXT $f; []; []; []; $($args)*,)
+    };
+}
+
+/// Flip the order of first two arguments, see [flip](basic_fn::fun::flip)
+///
+/// Syntax:
+/// 1. flip!(f) :: B -> A -> R, equals `flip(f)`
+/// 2. flip!(f, y) :: A -> R, equals `move |x| f(x, y)`
+/// 3. flip!(f, y, x) = f(x, y)
+/// 4. flip!(f, y, x, ...) = partial!(f, x, y, ...), for functions with more arguments
+#[macro_export] macro_rules! flip {
+    ($f:expr) => {move |y, x| $f(x, y)};
+    ($f:expr, $y:expr) => {move |x| $f(x, $y)};
+    ($f:expr, $y:expr, $x:expr) => {{$f($x, $y)}};
+    ($f:expr, $y:expr, $x:expr, $($rest:tt)*) => {
+        $crate::partial!($f, $x, $y, $($rest)*)
+    };
+}
+
+/// macro of [either](adt::either::either)
+///
+/// Syntax:
+/// 1. either!(f, g) :: [`Either`](adt::Either) L R -> T
+/// 2. either!(f, g, e) = either(f, g, e)
+#[macro_export] macro_rules! either {
+    ($f:expr,$g:expr) => {move |e| $crate::adt::either($f,$g,e)};
+    ($f:expr,$g:expr,$e:expr) => {{$crate::adt::either($f,$g,$e)}};
+}
+
+/// Build a [`NonEmptyVec`](adt::NonEmptyVec), at least one element is required
+///
+/// Syntax:
+/// nonempty![x, y, z...]
+#[macro_export] macro_rules! nonempty {
+    ($h:expr $(, $t:expr)* $(,)*) => {
+        $crate::adt::NonEmptyVec::from_parts($h, $crate::__private::vec![$($t),*])
+    };
+}
+
+/// Monadic do-notation, named `mdo!` as `do` is a reserved keyword.
+/// Statements are separated by semicolon(;) and desugared into nested [`Monad::bind`](typeclass::Monad::bind),
+/// so it works with [`Option`], [`Result`], [`Vec`] and any other [`Monad`](typeclass::Monad).
+/// Starting with `iter` desugars into [`Iterator::flat_map`] instead, for any [`IntoIterator`].
+///
+/// Statement:
+/// 1. `{Pattern} <- {Expr}`, bind the value in the monad, pattern must be irrefutable
+/// 2. `let {Pattern} = {Expr}`, plain binding
+/// 3. `{Expr}`, run for its effect and ignore the value, i.e. `>>` in Haskell
+///
+/// The last statement is the result, `pure(x)` lifts `x` into the monad without importing [`pure`](typeclass::pure).
+///
+/// Haskell form:
+/// ```haskell
+/// do { x <- Just 1; y <- Just 2; return (x + y) }
+/// ```
+/// Rust form:
+/// ```ignore
+/// mdo! { x <- Some(1); y <- Some(2); pure(x + y) }
+/// mdo! { iter x <- 1..3; y <- 'a'..'c'; pure((x, y)) }
+/// ```
+/// Every statement after a bind is a `move` closure which may run more than once for [`Vec`] and iterators,
+/// so clone non-[`Copy`] values used by later statements.
+#[macro_export] macro_rules! mdo {
+    (@DO $m:ident; let $p:pat = $e:expr; $($rest:tt)+) => {{
+        let $p = $e;
+        $crate::mdo!(@DO $m; $($rest)+)
+    }};
+    (@DO $m:ident; $($rest:tt)+) => {
+        $crate::mdo!(@STMT $m; []; $($rest)+)
+    };
+    (@STMT $m:ident; [$($p:tt)+]; <- $e:expr; $($rest:tt)+) => {
+        $crate::mdo!(@BIND $m; $e; move |$($p)+| $crate::mdo!(@DO $m; $($rest)+))
+    };
+    (@STMT $m:ident; [pure ($e:expr)]; $(;)*) => {
+        $crate::mdo!(@PURE $m; $e)
+    };
+    (@STMT $m:ident; [$($e:tt)+]; $(;)*) => {
+        ($($e)+)
+    };
+    (@STMT $m:ident; [$($e:tt)+]; ; $($rest:tt)+) => {
+        $crate::mdo!(@BIND $m; ($($e)+); move |_| $crate::mdo!(@DO $m; $($rest)+))
+    };
+    (@STMT $m:ident; [$($a:tt)*]; $t:tt $($rest:tt)*) => {
+        $crate::mdo!(@STMT $m; [$($a)* $t]; $($rest)*)
+    };
+    (@BIND bind; $e:expr; $f:expr) => {
+        $crate::typeclass::Monad::bind($e, $f)
+    };
+    (@BIND iter; $e:expr; $f:expr) => {
+        $crate::__private::iter::Iterator::flat_map($crate::__private::iter::IntoIterator::into_iter($e), $f)
+    };
+    (@PURE bind; $e:expr) => {
+        $crate::typeclass::Pure::pure($e)
+    };
+    (@PURE iter; $e:expr) => {
+        $crate::__private::iter::once($e)
+    };
+
+    (iter <- $($rest:tt)+) => {
+        $crate::mdo!(@DO bind; iter <- $($rest)+)
+    };
+    (iter $($rest:tt)+) => {
+        $crate::mdo!(@DO iter; $($rest)+)
+    };
+    ($($rest:tt)+) => {
+        $crate::mdo!(@DO bind; $($rest)+)
+    };
+}
+
+/// Apply wrapped functions to wrapped values, see [ap](typeclass::ap).
+/// Functions with more arguments must be curried, extra values are applied one by one.
+///
+/// Syntax:
+/// 1. ap!(fs) :: F T -> F U, equals `move |fa| ap(fs, fa)`
+/// 2. ap!(fs, fa) = ap(fs, fa)
+/// 3. ap!(fs, fa, fb...) = ap(ap(fs, fa), fb)...
+#[macro_export] macro_rules! ap {
+    (@NEXT $fs:expr;) => {
+        $fs
+    };
+    (@NEXT $fs:expr; $fa:expr $(, $rest:expr)*) => {
+        $crate::ap!(@NEXT $crate::typeclass::ap($fs, $fa); $($rest),*)
+    };
+    ($fs:expr) => {
+        move |fa| $crate::typeclass::ap($fs, fa)
+    };
+    ($fs:expr, $($fa:expr),+ $(,)*) => {
+        $crate::ap!(@NEXT $fs; $($fa),+)
+    };
+}
+
+/// Lift a function with n arguments over [`Applicative`](typeclass::Applicative), e.g. [`Option`], [`Result`] and [`Vec`].
+/// Values are paired by [`lift_a2`](typeclass::Applicative::lift_a2), so every value except the first must be [`Clone`].
+/// `_` is the placeholder of wrapped value like [partial!].
+///
+/// Syntax:
+/// 1. lift!(f, fa) = fmap(f, fa)
+/// 2. lift!(f, fa, fb, fc...) :: F R, `f` is applied to every combination of values
+/// 3. lift!(f, _, _) :: F A -> F B -> F R
+#[macro_export] macro_rules! lift {
+    (@NEXT $f:expr; [$($p:ident)*]; [$($a:expr),*]; _, $($rest:tt)*) => {
+        $crate::lift!(@NEXT $f; [$($p)* x]; [$($a,)* x]; $($rest)*)
+    };
+    (@NEXT $f:expr; [$($p:ident)*]; [$($a:expr),*]; $x:expr, $($rest:tt)*) => {
+        $crate::lift!(@NEXT $f; [$($p)*]; [$($a,)* $x]; $($rest)*)
+    };
+    (@NEXT $f:expr; []; [$($a:expr),+]; $(,)*) => {
+        $crate::lift!(@APPLY $f; $($a),+)
+    };
+    (@NEXT $f:expr; [$($p:ident)+]; [$($a:expr),+]; $(,)*) => {
+        move |$($p),+| $crate::lift!(@APPLY $f; $($a),+)
+    };
+    (@APPLY $f:expr; $a:expr) => {
+        $crate::typeclass::Functor::fmap($a, $f)
+    };
+    (@APPLY $f:expr; $a:expr, $($rest:expr),+) => {
+        $crate::lift!(@ZIP $f; $a; x; [x]; $($rest),+)
+    };
+    (@ZIP $f:expr; $acc:expr; $p:tt; [$($v:ident),+]; $a:expr, $($rest:expr),+) => {
+        $crate::lift!(@ZIP $f; $crate::typeclass::Applicative::lift_a2($acc, $a, |p, x| (p, x)); ($p, x); [$($v,)+ x]; $($rest),+)
+    };
+    (@ZIP $f:expr; $acc:expr; $p:tt; [$($v:ident),+]; $a:expr) => {{
+        #[allow(unused_mut)]
+        let mut f = $f;
+        $crate::typeclass::Functor::fmap(
+            $crate::typeclass::Applicative::lift_a2($acc, $a, |p, x| (p, x)),
+            move |($p, x)| f($($v,)+ x)
+        )
+    }};
+    ($f:expr, $($args:tt)+) => {
+        $crate::lift!(@NEXT $f; []; []; $($args)+,)
+    };
+}
+
+/// Feed one input to several branch functions and combine their results, the input is cloned for every branch.
+///
+/// Syntax:
+/// converge!(g, f1, f2...) :: T -> R, equals `move |x| g(f1(x.clone()), f2(x.clone())...)`
+#[macro_export] macro_rules! converge {
+    ($g:expr, $($f:expr),+ $(,)*) => {
+        move |x| $g($($f($crate::__private::clone::Clone::clone(&x))),+)
+    };
+}
+
+/// macro of [group_by](basic_fn::fun::group_by)
+///
+/// Syntax:
+/// 1. group_by!(f) :: [`Iterator`] T -> [`HashMap`](std::collections::HashMap) K [`Vec`] T
+/// 2. group_by!(f, it) = group_by(f, it)
+#[macro_export] macro_rules! group_by {
+    ($f:expr) => {move |it| $crate::basic_fn::fun::group_by($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::fun::group_by($f, $it)}};
+}
+
+/// macro of [group_with](basic_fn::fun::group_with)
+///
+/// Syntax:
+/// 1. group_with!(f) :: [`Iterator`] T -> [`Iterator`] [`Vec`] T
+/// 2. group_with!(f, it) = group_with(f, it)
+#[macro_export] macro_rules! group_with {
+    ($f:expr) => {move |it| $crate::basic_fn::fun::group_with($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::fun::group_with($f, $it)}};
+}
+
+/// macro of [partition](basic_fn::fun::partition)
+///
+/// Syntax:
+/// 1. partition!(f) :: [`Iterator`] T -> (B, B)
+/// 2. partition!(f, it) = partition(f, it)
+#[macro_export] macro_rules! partition {
+    ($f:expr) => {move |it| $crate::basic_fn::fun::partition($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::fun::partition($f, $it)}};
+}
+
+/// macro of [split_at](basic_fn::fun::split_at)
+///
+/// Syntax:
+/// 1. split_at!(n) :: [`Iterator`] T -> ([`Vec`] T, [`Iterator`] T)
+/// 2. split_at!(n, it) = split_at(n, it)
+#[macro_export] macro_rules! split_at {
+    ($n:expr) => {move |it| $crate::basic_fn::fun::split_at($n, it)};
+    ($n:expr,$it:expr) => {{$crate::basic_fn::fun::split_at($n, $it)}};
+}
+
+/// macro of [split_every](basic_fn::fun::split_every)
+///
+/// Syntax:
+/// 1. split_every!(n) :: [`Iterator`] T -> [`Iterator`] [`Vec`] T
+/// 2. split_every!(n, it) = split_every(n, it)
+#[macro_export] macro_rules! split_every {
+    ($n:expr) => {move |it| $crate::basic_fn::fun::split_every($n, it)};
+    ($n:expr,$it:expr) => {{$crate::basic_fn::fun::split_every($n, $it)}};
+}
+
+/// macro of [split_when](basic_fn::fun::split_when)
+///
+/// Syntax:
+/// 1. split_when!(f) :: [`Iterator`] T -> ([`Vec`] T, [`Iterator`] T)
+/// 2. split_when!(f, it) = split_when(f, it)
+#[macro_export] macro_rules! split_when {
+    ($f:expr) => {move |it| $crate::basic_fn::fun::split_when($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::fun::split_when($f, $it)}};
+}
+
+/// macro of [unique_by](basic_fn::fun::unique_by)
+///
+/// Syntax:
+/// 1. unique_by!(f) :: [`Iterator`] T -> [`Iterator`] T
+/// 2. unique_by!(f, it) = unique_by(f, it)
+#[macro_export] macro_rules! unique_by {
+    ($f:expr) => {move |it| $crate::basic_fn::fun::unique_by($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::fun::unique_by($f, $it)}};
+}
+
+/// macro of [unique_with](basic_fn::fun::unique_with)
+///
+/// Syntax:
+/// 1. unique_with!(eq) :: [`Iterator`] T -> [`Vec`] T
+/// 2. unique_with!(eq, it) = unique_with(eq, it)
+#[macro_export] macro_rules! unique_with {
+    ($eq:expr) => {move |it| $crate::basic_fn::fun::unique_with($eq, it)};
+    ($eq:expr,$it:expr) => {{$crate::basic_fn::fun::unique_with($eq, $it)}};
+}
+
+/// macro of [union](basic_fn::fun::union)
+///
+/// Syntax:
+/// 1. union!(it1) :: [`Iterator`] T -> [`Iterator`] T
+/// 2. union!(it1, it2) = union(it1, it2)
+#[macro_export] macro_rules! union {
+    ($it1:expr) => {move |it| $crate::basic_fn::fun::union($it1, it)};
+    ($it1:expr,$it2:expr) => {{$crate::basic_fn::fun::union($it1, $it2)}};
+}
+
+/// macro of [union_with](basic_fn::fun::union_with)
+///
+/// Syntax:
+/// 1. union_with!(eq) :: [`Iterator`] T -> [`Iterator`] T -> [`Vec`] T
+/// 2. union_with!(eq, it1) :: [`Iterator`] T -> [`Vec`] T
+/// 3. union_with!(eq, it1, it2) = union_with(eq, it1, it2)
+#[macro_export] macro_rules! union_with {
+    ($eq:expr) => {move |it1, it2| $crate::basic_fn::fun::union_with($eq, it1, it2)};
+    ($eq:expr,$it1:expr) => {move |it| $crate::basic_fn::fun::union_with($eq, $it1, it)};
+    ($eq:expr,$it1:expr,$it2:expr) => {{$crate::basic_fn::fun::union_with($eq, $it1, $it2)}};
+}
+
+/// macro of [intersection](basic_fn::fun::intersection)
+///
+/// Syntax:
+/// 1. intersection!(it1) :: [`Iterator`] T -> [`Iterator`] T
+/// 2. intersection!(it1, it2) = intersection(it1, it2)
+#[macro_export] macro_rules! intersection {
+    ($it1:expr) => {move |it| $crate::basic_fn::fun::intersection($it1, it)};
+    ($it1:expr,$it2:expr) => {{$crate::basic_fn::fun::intersection($it1, $it2)}};
+}
+
+/// macro of [diff](basic_fn::fun::diff)
+///
+/// Syntax:
+/// 1. diff!(it1) :: [`Iterator`] T -> [`Iterator`] T, elements of `it1` not in the argument
+/// 2. diff!(it1, it2) = diff(it1, it2)
+#[macro_export] macro_rules! diff {
+    ($it1:expr) => {move |it| $crate::basic_fn::fun::diff($it1, it)};
+    ($it1:expr,$it2:expr) => {{$crate::basic_fn::fun::diff($it1, $it2)}};
+}
+
+/// macro of [without](basic_fn::fun::without)
+///
+/// Syntax:
+/// 1. without!(xs) :: [`Iterator`] T -> [`Iterator`] T
+/// 2. without!(xs, it) = without(xs, it)
+#[macro_export] macro_rules! without {
+    ($xs:expr) => {move |it| $crate::basic_fn::fun::without($xs, it)};
+    ($xs:expr,$it:expr) => {{$crate::basic_fn::fun::without($xs, $it)}};
+}
@@ -9,5 +9,8 @@
 //! This include function and macros. For details please refer [Rust Docs](https://docs.rs/)
 
 pub mod fun;
+pub mod adapter;
 #[macro_use]
-pub mod mac;
\ No newline at end of file
+pub mod mac;
+#[cfg(feature = "parallel")]
+pub mod par;
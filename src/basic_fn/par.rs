@@ -0,0 +1,177 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parallel versions of basic functions, enabled by feature `parallel`.
+//!
+//! They run on the work-stealing thread pool of [rayon](https://docs.rs/rayon) and take anything
+//! [`IntoParallelIterator`], e.g. [`Vec`], slices and ranges. Results keep the order of the input.
+//!
+//! ```
+//! # #[macro_use] extern crate pa;
+//! # use pa::basic_fn::par::*;
+//! # fn main() {
+//! let squares = par_pipe!(par |x: u64| x * x, par_sum);
+//! assert_eq!(squares((1..=100).collect::<Vec<_>>()), 338350);
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::iter::Sum;
+
+use rayon::iter::{Filter, IntoParallelIterator, Map, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+use rayon::vec::IntoIter;
+
+use crate::typeclass::Monoid;
+
+/// Parallel [map](basic_fn::fun::map)
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> R
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_map<I, R, F>(f: F, it: I) -> Map<I::Iter, F>
+    where I: IntoParallelIterator, R: Send, F: Fn(I::Item) -> R + Sync + Send
+{
+    it.into_par_iter().map(f)
+}
+
+/// Eager [par_map] collecting into [`Vec`]
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> R
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_map_vec<I, R, F>(f: F, it: I) -> Vec<R>
+    where I: IntoParallelIterator, R: Send, F: Fn(I::Item) -> R + Sync + Send
+{
+    par_map(f, it).collect()
+}
+
+/// Parallel [filter](basic_fn::fun::filter)
+///
+/// # Arguments
+///
+/// * `f`: f :: &T -> [`bool`]
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_filter<I, F>(f: F, it: I) -> Filter<I::Iter, F>
+    where I: IntoParallelIterator, F: Fn(&I::Item) -> bool + Sync + Send
+{
+    it.into_par_iter().filter(f)
+}
+
+/// Parallel fold into a [`Monoid`]. Every chunk is folded from [mempty](typeclass::mempty)
+/// and chunks are combined in order by [mappend](typeclass::mappend), so it must be associative.
+///
+/// # Arguments
+///
+/// * `f`: f :: (M, T) -> M, fold function
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_fold<I, M, F>(f: F, it: I) -> M
+    where I: IntoParallelIterator, M: Monoid + Send, F: Fn(M, I::Item) -> M + Sync + Send
+{
+    it.into_par_iter().fold(M::mempty, f).reduce(M::mempty, M::mappend)
+}
+
+/// Parallel fold with an associative `combine` whose identity is `init`
+///
+/// # Arguments
+///
+/// * `init`: initial point of folding every chunk, identity of `combine`
+/// * `f`: f :: (R, T) -> R, fold function
+/// * `combine`: combine :: (R, R) -> R, associative function combining folded chunks
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_fold_with<I, R, F, C>(init: R, f: F, combine: C, it: I) -> R
+    where I: IntoParallelIterator, R: Clone + Send + Sync, F: Fn(R, I::Item) -> R + Sync + Send, C: Fn(R, R) -> R + Sync + Send
+{
+    it.into_par_iter().fold(|| init.clone(), f).reduce(|| init.clone(), combine)
+}
+
+/// Parallel [sorted](basic_fn::fun::sorted)
+///
+/// # Arguments
+///
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_sorted<I>(it: I) -> IntoIter<I::Item>
+    where I: IntoParallelIterator, I::Item: Ord
+{
+    let mut tmp: Vec<_> = it.into_par_iter().collect();
+    tmp.par_sort_unstable();
+    tmp.into_par_iter()
+}
+
+/// Parallel [sorted_by](basic_fn::fun::sorted_by)
+///
+/// # Arguments
+///
+/// * `f`: (&T -> &T) -> [`Ordering`]
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_sorted_by<I, F>(f: F, it: I) -> IntoIter<I::Item>
+    where I: IntoParallelIterator, F: Fn(&I::Item, &I::Item) -> Ordering + Sync
+{
+    let mut tmp: Vec<_> = it.into_par_iter().collect();
+    tmp.par_sort_unstable_by(f);
+    tmp.into_par_iter()
+}
+
+/// Parallel [sum](basic_fn::fun::sum)
+///
+/// # Arguments
+///
+/// * `it`: [`IntoParallelIterator`] T
+pub fn par_sum<I>(it: I) -> I::Item
+    where I: IntoParallelIterator, I::Item: Sum + Send
+{
+    it.into_par_iter().sum()
+}
+
+/// Curry macro of [par_map](basic_fn::par::par_map)
+///
+/// **Signature**: par_map :: (T -> R) -> [`IntoParallelIterator`] T -> [`ParallelIterator`] R
+#[macro_export] macro_rules! par_map {
+    ($f:expr) => {move |it| $crate::basic_fn::par::par_map($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::par::par_map($f, $it)}};
+}
+
+/// Curry macro of [par_filter](basic_fn::par::par_filter)
+///
+/// **Signature**: par_filter :: (&T -> bool) -> [`IntoParallelIterator`] T -> [`ParallelIterator`] T
+#[macro_export] macro_rules! par_filter {
+    ($f:expr) => {move |it| $crate::basic_fn::par::par_filter($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::par::par_filter($f, $it)}};
+}
+
+/// Curry macro of [par_fold](basic_fn::par::par_fold)
+///
+/// **Signature**: par_fold :: [`Monoid`] M => (M -> T -> M) -> [`IntoParallelIterator`] T -> M
+#[macro_export] macro_rules! par_fold {
+    ($f:expr) => {move |it| $crate::basic_fn::par::par_fold($f, it)};
+    ($f:expr,$it:expr) => {{$crate::basic_fn::par::par_fold($f, $it)}};
+}
+
+/// [pipe!](crate::pipe) whose stages prefixed by `par` map every element in parallel, collecting into [`Vec`].
+/// Other stages are applied to the whole value as in [pipe!](crate::pipe).
+///
+/// Syntax:
+/// par_pipe!(f, par g, h) = |v| h(par_map_vec(g, f(v)))
+#[macro_export] macro_rules! par_pipe {
+    (@NEXT $v:expr; par $f:expr, $($rest:tt)*) => {
+        $crate::par_pipe!(@NEXT $crate::basic_fn::par::par_map_vec($f, $v); $($rest)*)
+    };
+    (@NEXT $v:expr; par $f:expr) => {
+        $crate::basic_fn::par::par_map_vec($f, $v)
+    };
+    (@NEXT $v:expr; $f:expr, $($rest:tt)*) => {
+        $crate::par_pipe!(@NEXT $f($v); $($rest)*)
+    };
+    (@NEXT $v:expr; $f:expr) => {
+        $f($v)
+    };
+    ($($stage:tt)*) => {
+        |v| {$crate::par_pipe!(@NEXT v; $($stage)*)}
+    };
+}
@@ -0,0 +1,148 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cell::LazyCell;
+use core::fmt;
+use core::ops::Deref;
+use alloc::rc::Rc;
+use alloc::boxed::Box;
+
+/// Memoised thunk, the value is computed on first [force](Lazy::force) and clones share it
+pub struct Lazy<T>(Rc<LazyCell<T, Box<dyn FnOnce() -> T>>>);
+
+impl<T> Lazy<T> {
+    /// Thunk computed by `f` on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: () -> T
+    pub fn new(f: impl FnOnce() -> T + 'static) -> Self {
+        Lazy(Rc::new(LazyCell::new(Box::new(f))))
+    }
+
+    /// Getting the value, computing it if it is the first time
+    pub fn force(&self) -> &T {
+        LazyCell::force(&self.0)
+    }
+}
+
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Lazy(self.0.clone())
+    }
+}
+
+impl<T> Deref for Lazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+/// The value is computed for showing
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Lazy").field(self.force()).finish()
+    }
+}
+
+/// A value with its evaluation strategy, as `Eval` in Scala cats.
+///
+/// Clones share the strategy, i.e. [`Later`](Eval::Later) is computed once for all clones
+/// while [`Always`](Eval::Always) is computed for every [value](Eval::value).
+/// Use [`Trampoline`](lazy::Trampoline) for deep recursion, `Eval` converts into it.
+pub enum Eval<T> {
+    /// Computed eagerly
+    Now(T),
+    /// Computed on demand and memoised
+    Later(Lazy<T>),
+    /// Computed on demand every time
+    Always(Rc<dyn Fn() -> T>),
+}
+
+impl<T> Eval<T> {
+    /// Value computed eagerly
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn now(x: T) -> Self {
+        Eval::Now(x)
+    }
+
+    /// Value computed by `f` on demand and memoised
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: () -> T
+    pub fn later(f: impl FnOnce() -> T + 'static) -> Self {
+        Eval::Later(Lazy::new(f))
+    }
+
+    /// Value computed by `f` on demand without memoisation
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: () -> T
+    pub fn always(f: impl Fn() -> T + 'static) -> Self {
+        Eval::Always(Rc::new(f))
+    }
+
+    /// Getting the value by its strategy
+    pub fn value(&self) -> T where T: Clone {
+        match *self {
+            Eval::Now(ref x) => x.clone(),
+            Eval::Later(ref lazy) => lazy.force().clone(),
+            Eval::Always(ref f) => f(),
+        }
+    }
+
+    /// Lazily map the value, the result is [`Later`](Eval::Later)
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: T -> U
+    pub fn map<U>(&self, f: impl FnOnce(T) -> U + 'static) -> Eval<U> where T: Clone + 'static {
+        let e = self.clone();
+        Eval::later(move || f(e.value()))
+    }
+
+    /// Lazily sequence by a function returning next [`Eval`], the result is [`Later`](Eval::Later)
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: T -> [`Eval`] U
+    pub fn flat_map<U: Clone>(&self, f: impl FnOnce(T) -> Eval<U> + 'static) -> Eval<U> where T: Clone + 'static {
+        let e = self.clone();
+        Eval::later(move || f(e.value()).value())
+    }
+
+    /// Memoise [`Always`](Eval::Always), others are unchanged
+    pub fn memoize(self) -> Eval<T> where T: 'static {
+        match self {
+            Eval::Always(f) => Eval::later(move || f()),
+            e => e,
+        }
+    }
+}
+
+impl<T: Clone> Clone for Eval<T> {
+    fn clone(&self) -> Self {
+        match *self {
+            Eval::Now(ref x) => Eval::Now(x.clone()),
+            Eval::Later(ref lazy) => Eval::Later(lazy.clone()),
+            Eval::Always(ref f) => Eval::Always(f.clone()),
+        }
+    }
+}
+
+impl<T> From<Lazy<T>> for Eval<T> {
+    fn from(lazy: Lazy<T>) -> Self {
+        Eval::Later(lazy)
+    }
+}
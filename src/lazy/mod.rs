@@ -0,0 +1,28 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module lazy provide values which are computed on demand and memoised.
+//!
+//! [`Stream`] is a lazy cons list, so an infinite sequence can be traversed more than once
+//! and every element is computed only once.
+//! [`Lazy`] is a memoised thunk and [`Eval`] chooses between eager, memoised and repeated evaluation.
+//! [`Trampoline`] runs deep recursion without growing the stack.
+//!
+//! ```
+//! # use pa::lazy::*;
+//! let fib = unfold((0u64, 1u64), |(a, b)| Some((a, (b, a + b))));
+//! assert_eq!(fib.iter().take(5).cloned().collect::<Vec<_>>(), [0, 1, 1, 2, 3]);
+//! assert_eq!(fib.iter().nth(50), Some(&12586269025));
+//! ```
+
+pub mod stream;
+pub mod eval;
+pub mod trampoline;
+
+pub use self::stream::{Stream, unfold, iterate, repeat, cycle, times};
+pub use self::eval::{Lazy, Eval};
+pub use self::trampoline::Trampoline;
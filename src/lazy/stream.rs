@@ -0,0 +1,367 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cell::{Cell, OnceCell};
+use core::fmt;
+use core::iter::FromIterator;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+type Cons<T> = Option<(T, Stream<T>)>;
+type Init<T> = Box<dyn FnOnce() -> Cons<T>>;
+
+struct Thunk<T> {
+    value: OnceCell<Cons<T>>,
+    init: Cell<Option<Init<T>>>,
+}
+
+impl<T> Thunk<T> {
+    fn take_tail(&mut self) -> Option<Stream<T>> {
+        self.value.take().and_then(|cons| cons.map(|(_, tail)| tail))
+    }
+}
+
+/// Lazy cons list, i.e. list in Haskell.
+///
+/// Every cell is computed on first access and memoised, clones share computed cells,
+/// so an infinite stream can be traversed more than once without recomputation.
+/// Combinators only build new cells, nothing is computed until the stream is traversed.
+pub struct Stream<T>(Rc<Thunk<T>>);
+
+impl<T> Stream<T> {
+    /// Empty stream
+    pub fn empty() -> Self {
+        Stream(Rc::new(Thunk { value: OnceCell::from(None), init: Cell::new(None) }))
+    }
+
+    /// Prepend an element to a stream, i.e. `x : xs` in Haskell
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    /// * `tail`: [`Stream`] T
+    pub fn cons(x: T, tail: Stream<T>) -> Self {
+        Stream(Rc::new(Thunk { value: OnceCell::from(Some((x, tail))), init: Cell::new(None) }))
+    }
+
+    fn force(&self) -> Option<&(T, Stream<T>)> {
+        self.0.value.get_or_init(|| match self.0.init.take() {
+            Some(f) => f(),
+            None => panic!("Stream is forced recursively"),
+        }).as_ref()
+    }
+
+    /// Getting the first element, `None` if empty
+    pub fn head(&self) -> Option<&T> {
+        self.force().map(|(x, _)| x)
+    }
+
+    /// Getting all elements except first, `None` if empty
+    pub fn tail(&self) -> Option<Stream<T>> {
+        self.force().map(|(_, tail)| tail.clone())
+    }
+
+    /// Check if there is no element, the first cell is computed
+    pub fn is_empty(&self) -> bool {
+        self.force().is_none()
+    }
+
+    /// [`Iterator`] over references of elements from the head, computing cells on demand
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: Some(self) }
+    }
+}
+
+impl<T: 'static> Stream<T> {
+    /// Stream whose cells are computed by `f` on first access
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: () -> [`Option`] (T, [`Stream`] T), `None` for empty stream
+    pub fn new(f: impl FnOnce() -> Option<(T, Stream<T>)> + 'static) -> Self {
+        Stream(Rc::new(Thunk { value: OnceCell::new(), init: Cell::new(Some(Box::new(f))) }))
+    }
+
+    /// Stream pulling from an [`Iterator`] on demand, unlike [`FromIterator`] which consumes it at once
+    ///
+    /// # Arguments
+    ///
+    /// * `it`: [`Iterator`] T
+    pub fn from_iterator(it: impl Iterator<Item=T> + 'static) -> Self {
+        unfold(it, |mut it| it.next().map(|x| (x, it)))
+    }
+
+    /// Lazily map every element
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: &T -> U
+    pub fn map<U: 'static>(&self, mut f: impl FnMut(&T) -> U + 'static) -> Stream<U> {
+        let s = self.clone();
+        Stream::new(move || s.force().map(|(x, tail)| (f(x), tail.map(f))))
+    }
+
+    /// Lazily keep elements satisfying the predicate
+    ///
+    /// # Arguments
+    ///
+    /// * `p`: p :: &T -> [`bool`]
+    pub fn filter(&self, mut p: impl FnMut(&T) -> bool + 'static) -> Stream<T> where T: Clone {
+        let s = self.clone();
+        Stream::new(move || {
+            let mut cur = s;
+            loop {
+                let next = match cur.force() {
+                    None => return None,
+                    Some((x, tail)) if p(x) => return Some((x.clone(), tail.filter(p))),
+                    Some((_, tail)) => tail.clone(),
+                };
+                cur = next;
+            }
+        })
+    }
+
+    /// Lazily take first n elements
+    ///
+    /// # Arguments
+    ///
+    /// * `n`: elements count to take
+    pub fn take(&self, n: usize) -> Stream<T> where T: Clone {
+        if n == 0 {
+            return Stream::empty();
+        }
+        let s = self.clone();
+        Stream::new(move || s.force().map(|(x, tail)| (x.clone(), tail.take(n - 1))))
+    }
+
+    /// Lazily take elements until the predicate fails
+    ///
+    /// # Arguments
+    ///
+    /// * `p`: p :: &T -> [`bool`]
+    pub fn take_while(&self, mut p: impl FnMut(&T) -> bool + 'static) -> Stream<T> where T: Clone {
+        let s = self.clone();
+        Stream::new(move || match s.force() {
+            Some((x, tail)) if p(x) => Some((x.clone(), tail.take_while(p))),
+            _ => None,
+        })
+    }
+
+    /// Lazily fold with every intermediate result, starting from `init`
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: first element of result
+    /// * `f`: f :: (&U, &T) -> U
+    pub fn scan<U: Clone + 'static>(&self, init: U, f: impl FnMut(&U, &T) -> U + 'static) -> Stream<U> {
+        Stream::cons(init.clone(), scan_tail(self.clone(), init, f))
+    }
+
+    /// Lazily pair elements of two streams, stops at the shorter one
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [`Stream`] U
+    pub fn zip<U: Clone + 'static>(&self, other: &Stream<U>) -> Stream<(T, U)> where T: Clone {
+        self.zip_with(other, |x, y| (x.clone(), y.clone()))
+    }
+
+    /// Lazily combine elements of two streams by a function, stops at the shorter one
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [`Stream`] U
+    /// * `f`: f :: (&T, &U) -> V
+    pub fn zip_with<U: 'static, V: 'static>(&self, other: &Stream<U>, mut f: impl FnMut(&T, &U) -> V + 'static) -> Stream<V> {
+        let (s1, s2) = (self.clone(), other.clone());
+        Stream::new(move || {
+            let (x, tail1) = s1.force()?;
+            let (y, tail2) = s2.force()?;
+            Some((f(x, y), tail1.zip_with(tail2, f)))
+        })
+    }
+
+    /// Lazily take elements of two streams in turn, the rest of the longer one follows
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [`Stream`] T
+    pub fn interleave(&self, other: &Stream<T>) -> Stream<T> where T: Clone {
+        let (s1, s2) = (self.clone(), other.clone());
+        Stream::new(move || match s1.force() {
+            Some((x, tail)) => Some((x.clone(), s2.interleave(tail))),
+            None => s2.force().map(|(x, tail)| (x.clone(), tail.clone())),
+        })
+    }
+}
+
+fn scan_tail<T: 'static, U: Clone + 'static>(s: Stream<T>, acc: U, mut f: impl FnMut(&U, &T) -> U + 'static) -> Stream<U> {
+    Stream::new(move || {
+        let (x, tail) = s.force()?;
+        let next = f(&acc, x);
+        Some((next.clone(), scan_tail(tail.clone(), next, f)))
+    })
+}
+
+/// Build a stream from a seed, `f` returns next element with next seed, or `None` to stop
+///
+/// # Arguments
+///
+/// * `seed`: S
+/// * `f`: f :: S -> [`Option`] (T, S)
+pub fn unfold<S: 'static, T: 'static>(seed: S, mut f: impl FnMut(S) -> Option<(T, S)> + 'static) -> Stream<T> {
+    Stream::new(move || {
+        let (x, next) = f(seed)?;
+        Some((x, unfold(next, f)))
+    })
+}
+
+/// Infinite stream of repeated applications, i.e. `x, f(x), f(f(x))...`
+///
+/// # Arguments
+///
+/// * `x`: T
+/// * `f`: f :: &T -> T
+pub fn iterate<T: 'static>(x: T, mut f: impl FnMut(&T) -> T + 'static) -> Stream<T> {
+    unfold(x, move |x| {
+        let next = f(&x);
+        Some((x, next))
+    })
+}
+
+/// Infinite stream of the same element
+///
+/// # Arguments
+///
+/// * `x`: T
+pub fn repeat<T: Clone + 'static>(x: T) -> Stream<T> {
+    unfold(x, |x| Some((x.clone(), x)))
+}
+
+/// Infinite stream repeating elements in order, empty if there is no element
+///
+/// # Arguments
+///
+/// * `it`: [`IntoIterator`] T
+pub fn cycle<T: Clone + 'static>(it: impl IntoIterator<Item=T>) -> Stream<T> {
+    let items: Vec<T> = it.into_iter().collect();
+    unfold(0, move |i| items.get(i).map(|x| (x.clone(), (i + 1) % items.len())))
+}
+
+/// Stream of `f(0), f(1)... f(n - 1)`
+///
+/// # Arguments
+///
+/// * `n`: count of elements
+/// * `f`: f :: [`usize`] -> T
+pub fn times<T: 'static>(n: usize, mut f: impl FnMut(usize) -> T + 'static) -> Stream<T> {
+    unfold(0, move |i| if i < n { Some((f(i), i + 1)) } else { None })
+}
+
+/// Drop cells one by one, a long computed stream would overflow the stack by recursive drop otherwise
+impl<T> Drop for Stream<T> {
+    fn drop(&mut self) {
+        let mut next = Rc::get_mut(&mut self.0).and_then(Thunk::take_tail);
+        while let Some(mut s) = next {
+            next = Rc::get_mut(&mut s.0).and_then(Thunk::take_tail);
+        }
+    }
+}
+
+impl<T> Clone for Stream<T> {
+    fn clone(&self) -> Self {
+        Stream(self.0.clone())
+    }
+}
+
+impl<T> Default for Stream<T> {
+    fn default() -> Self {
+        Stream::empty()
+    }
+}
+
+/// Only computed cells are shown, `..` stands for the rest
+impl<T: fmt::Debug> fmt::Debug for Stream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut cur = self;
+        loop {
+            match cur.0.value.get() {
+                Some(Some((x, tail))) => {
+                    list.entry(x);
+                    cur = tail;
+                }
+                Some(None) => return list.finish(),
+                None => return list.entry(&format_args!("..")).finish(),
+            }
+        }
+    }
+}
+
+/// Collect the [`Iterator`] at once, see [from_iterator](Stream::from_iterator) for lazy version
+impl<T: 'static> FromIterator<T> for Stream<T> {
+    fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        Stream::from_iterator(items.into_iter())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stream<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Clone> IntoIterator for Stream<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { next: self }
+    }
+}
+
+/// [`Iterator`] of [`Stream`]
+pub struct Iter<'a, T: 'a> {
+    next: Option<&'a Stream<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self.next.and_then(Stream::force) {
+            Some((x, tail)) => {
+                self.next = Some(tail);
+                Some(x)
+            }
+            None => {
+                self.next = None;
+                None
+            }
+        }
+    }
+}
+
+/// Owned [`Iterator`] of [`Stream`], elements are cloned as cells may be shared
+pub struct IntoIter<T> {
+    next: Stream<T>,
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (x, tail) = self.next.force().map(|(x, tail)| (x.clone(), tail.clone()))?;
+        self.next = tail;
+        Some(x)
+    }
+}
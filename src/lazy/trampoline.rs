@@ -0,0 +1,128 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cell::Cell;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use super::eval::Eval;
+
+/// Result of a finished step is left in the typed [`Slot`] of its [`Trampoline`],
+/// so steps and continuations don't need to know the type.
+type Slot<T> = Rc<Cell<Option<T>>>;
+type Cont<'a> = Box<dyn FnOnce() -> Step<'a> + 'a>;
+
+enum Step<'a> {
+    Done,
+    Defer(Cont<'a>),
+    FlatMap(Box<Step<'a>>, Cont<'a>),
+}
+
+/// Stack-safe computation, recursion is described by [defer](Trampoline::defer) and
+/// [flat_map](Trampoline::flat_map) and [run](Trampoline::run) in a loop with heap allocated continuations.
+/// Continuations may borrow data living for `'a`.
+///
+/// ```
+/// # use pa::lazy::Trampoline;
+/// fn sum(n: u64) -> Trampoline<'static, u64> {
+///     if n == 0 {
+///         Trampoline::done(0)
+///     } else {
+///         Trampoline::defer(move || sum(n - 1)).map(move |s| s + n)
+///     }
+/// }
+/// assert_eq!(sum(1_000_000).run(), 500_000_500_000);
+/// ```
+pub struct Trampoline<'a, T> {
+    step: Step<'a>,
+    slot: Slot<T>,
+}
+
+impl<'a, T: 'a> Trampoline<'a, T> {
+    /// Computation finished with value
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn done(x: T) -> Self {
+        Trampoline { step: Step::Done, slot: Rc::new(Cell::new(Some(x))) }
+    }
+
+    /// Suspend a computation, e.g. a recursive call, until it is run
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: () -> [`Trampoline`] T
+    pub fn defer(f: impl FnOnce() -> Trampoline<'a, T> + 'a) -> Self {
+        let slot = Rc::new(Cell::new(None));
+        let out = slot.clone();
+        Trampoline { step: Step::Defer(Box::new(move || f().forward(out))), slot }
+    }
+
+    /// Sequence by a function returning next computation
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: T -> [`Trampoline`] U
+    pub fn flat_map<U: 'a>(self, f: impl FnOnce(T) -> Trampoline<'a, U> + 'a) -> Trampoline<'a, U> {
+        let slot = Rc::new(Cell::new(None));
+        let (out, input) = (slot.clone(), self.slot);
+        let cont: Cont<'a> = Box::new(move || f(take(&input)).forward(out));
+        Trampoline { step: Step::FlatMap(Box::new(self.step), cont), slot }
+    }
+
+    /// Map the result
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: f :: T -> U
+    pub fn map<U: 'a>(self, f: impl FnOnce(T) -> U + 'a) -> Trampoline<'a, U> {
+        self.flat_map(move |x| Trampoline::done(f(x)))
+    }
+
+    /// Run the computation in constant stack space
+    pub fn run(self) -> T {
+        let mut conts: Vec<Cont<'a>> = Vec::new();
+        let mut cur = self.step;
+        loop {
+            cur = match cur {
+                Step::Done => match conts.pop() {
+                    Some(k) => k(),
+                    None => return take(&self.slot),
+                },
+                Step::Defer(f) => f(),
+                Step::FlatMap(m, k) => {
+                    conts.push(k);
+                    *m
+                }
+            };
+        }
+    }
+
+    /// Step running `self` and then moving its result into `out`
+    fn forward(self, out: Slot<T>) -> Step<'a> {
+        let input = self.slot;
+        Step::FlatMap(Box::new(self.step), Box::new(move || {
+            out.set(input.take());
+            Step::Done
+        }))
+    }
+}
+
+fn take<T>(slot: &Slot<T>) -> T {
+    slot.take().expect("Trampoline step finished without value")
+}
+
+impl<'a, T: Clone + 'a> From<Eval<T>> for Trampoline<'a, T> {
+    fn from(e: Eval<T>) -> Self {
+        match e {
+            Eval::Now(x) => Trampoline::done(x),
+            e => Trampoline::defer(move || Trampoline::done(e.value())),
+        }
+    }
+}
@@ -8,11 +8,50 @@
 //! KaguyaRs aims to provide a common functional programming lib on Rust.
 //! 
 //! Basic functions and ADTs with implementation will be provided as much as I can.
+//!
+//! The crate is `no_std` without the default feature `std`. Feature `alloc` enables functions and
+//! modules needing heap allocation, `std` adds the ones based on `HashMap`, `HashSet` and threads.
 
 // for crate docs
 // #![feature(universal_impl_trait,conservative_impl_trait)]
 
-pub mod basic_fn {
-    pub mod fun;
-    pub mod mac;
-}
\ No newline at end of file
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+extern crate pa_macros;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[cfg(feature = "async_fn")]
+extern crate futures;
+
+pub use pa_macros::curry;
+
+/// Paths used by macros, so they expand to the same items with or without `std`
+#[doc(hidden)]
+pub mod __private {
+    pub use core::{clone, iter};
+    #[cfg(feature = "alloc")]
+    pub use alloc::{boxed, vec};
+}
+
+pub mod basic_fn;
+
+pub mod typeclass;
+#[cfg(feature = "alloc")]
+pub mod persistent;
+#[cfg(feature = "alloc")]
+pub mod optics;
+#[cfg(feature = "alloc")]
+pub mod transducer;
+pub mod adt;
+pub mod num;
+#[cfg(feature = "alloc")]
+pub mod lazy;
+#[cfg(feature = "std")]
+pub mod memo;
+#[cfg(feature = "async_fn")]
+pub mod async_fn;
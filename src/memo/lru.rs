@@ -0,0 +1,162 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+struct Entry<V> {
+    value: V,
+    tick: u64,
+    created: Instant,
+}
+
+/// Cache bounded by capacity, the least recently used entry is evicted when it is full.
+/// Entries can also expire after a time-to-live.
+pub struct LruCache<K, V> {
+    capacity: usize,
+    ttl: Option<Duration>,
+    tick: u64,
+    entries: HashMap<K, Entry<V>>,
+    order: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Empty cache holding at most `capacity` entries
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: max count of entries
+    pub fn new(capacity: usize) -> Self {
+        LruCache { capacity, ttl: None, tick: 0, entries: HashMap::new(), order: BTreeMap::new() }
+    }
+
+    /// Expire entries `ttl` after they are inserted
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl`: time-to-live of entries
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Getting the value of a key and mark it as recently used, `None` if it is absent or expired
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: &K
+    pub fn get(&mut self, k: &K) -> Option<&V> {
+        let expired = match (self.entries.get(k), self.ttl) {
+            (None, _) => return None,
+            (Some(e), Some(ttl)) => e.created.elapsed() >= ttl,
+            (Some(_), None) => false,
+        };
+        if expired {
+            self.remove(k);
+            return None;
+        }
+        let tick = self.next_tick();
+        let entry = self.entries.get_mut(k)?;
+        let key = self.order.remove(&entry.tick)?;
+        self.order.insert(tick, key);
+        entry.tick = tick;
+        Some(&entry.value)
+    }
+
+    /// Insert or replace a value, evicting the least recently used entry if the cache is full
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: K
+    /// * `v`: V
+    pub fn insert(&mut self, k: K, v: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.remove(&k);
+        if self.entries.len() == self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        let tick = self.next_tick();
+        self.order.insert(tick, k.clone());
+        self.entries.insert(k, Entry { value: v, tick, created: Instant::now() });
+    }
+
+    /// Remove an entry, returning its value
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: &K
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.entries.remove(k).map(|e| {
+            self.order.remove(&e.tick);
+            e.value
+        })
+    }
+
+    /// Count of entries, expired ones are included until they are accessed
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there is no entry
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn memoize_in<A, R>(cache: LruCache<A, R>, f: impl Fn(A) -> R) -> impl Fn(A) -> R
+    where A: Hash + Eq + Clone, R: Clone
+{
+    let cache = RefCell::new(cache);
+    move |x| {
+        if let Some(r) = cache.borrow_mut().get(&x) {
+            return R::clone(r);
+        }
+        let r = f(x.clone());
+        cache.borrow_mut().insert(x, r.clone());
+        r
+    }
+}
+
+/// [memoize](memo::memoize) with at most `capacity` results, the least recently used one is evicted
+///
+/// # Arguments
+///
+/// * `capacity`: max count of cached results
+/// * `f`: f :: A -> R
+///
+/// **Signature**: [`usize`] -> (A -> R) -> A -> R
+pub fn memoize_lru<A, R>(capacity: usize, f: impl Fn(A) -> R) -> impl Fn(A) -> R
+    where A: Hash + Eq + Clone, R: Clone
+{
+    memoize_in(LruCache::new(capacity), f)
+}
+
+/// [memoize_lru] whose results expire `ttl` after they are computed
+///
+/// # Arguments
+///
+/// * `capacity`: max count of cached results
+/// * `ttl`: time-to-live of results
+/// * `f`: f :: A -> R
+///
+/// **Signature**: [`usize`] -> [`Duration`] -> (A -> R) -> A -> R
+pub fn memoize_ttl<A, R>(capacity: usize, ttl: Duration, f: impl Fn(A) -> R) -> impl Fn(A) -> R
+    where A: Hash + Eq + Clone, R: Clone
+{
+    memoize_in(LruCache::new(capacity).with_ttl(ttl), f)
+}
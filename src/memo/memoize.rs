@@ -0,0 +1,85 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+/// Cache results of a function by its argument, `f` is called once for every distinct argument.
+/// Use a tuple as argument for functions with more arguments.
+///
+/// # Arguments
+///
+/// * `f`: f :: A -> R
+///
+/// **Signature**: (A -> R) -> A -> R
+pub fn memoize<A, R>(f: impl Fn(A) -> R) -> impl Fn(A) -> R
+    where A: Hash + Eq + Clone, R: Clone
+{
+    memoize_with(A::clone, f)
+}
+
+/// Cache results of a function by a key generated from its argument, i.e. `memoizeWith` in Ramda
+///
+/// # Arguments
+///
+/// * `key`: key :: &A -> K
+/// * `f`: f :: A -> R
+///
+/// **Signature**: (&A -> K) -> (A -> R) -> A -> R
+pub fn memoize_with<A, K, R>(key: impl Fn(&A) -> K, f: impl Fn(A) -> R) -> impl Fn(A) -> R
+    where K: Hash + Eq, R: Clone
+{
+    let cache = RefCell::new(HashMap::new());
+    move |x| {
+        let k = key(&x);
+        if let Some(r) = cache.borrow().get(&k) {
+            return R::clone(r);
+        }
+        let r = f(x);
+        cache.borrow_mut().insert(k, r.clone());
+        r
+    }
+}
+
+/// Thread-safe [memoize], the cache is shared by all threads.
+/// The lock is not held while calling `f`, so `f` may run more than once for an argument under contention.
+///
+/// # Arguments
+///
+/// * `f`: f :: A -> R
+///
+/// **Signature**: (A -> R) -> A -> R
+pub fn memoize_sync<A, R>(f: impl Fn(A) -> R + Send + Sync) -> impl Fn(A) -> R + Send + Sync
+    where A: Hash + Eq + Clone + Send, R: Clone + Send
+{
+    let cache = Mutex::new(HashMap::new());
+    move |x: A| {
+        if let Some(r) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&x) {
+            return R::clone(r);
+        }
+        let r = f(x.clone());
+        cache.lock().unwrap_or_else(|e| e.into_inner()).insert(x, r.clone());
+        r
+    }
+}
+
+/// Run a function at most once, later calls return the first result
+///
+/// # Arguments
+///
+/// * `f`: f :: () -> R
+///
+/// **Signature**: (() -> R) -> () -> R
+pub fn once<R: Clone>(f: impl FnOnce() -> R) -> impl Fn() -> R {
+    let f = Cell::new(Some(f));
+    let result = OnceCell::new();
+    move || {
+        result.get_or_init(|| f.take().expect("once is called recursively")()).clone()
+    }
+}
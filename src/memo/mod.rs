@@ -0,0 +1,30 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module memo provide caching of function results.
+//!
+//! Memoized closures keep their cache inside, so a closure cannot reuse results of its own recursive calls.
+//! Use `#[memoize]` attribute for recursive functions, e.g. fibonacci:
+//!
+//! ```
+//! # extern crate pa;
+//! # use pa::memo::memoize;
+//! #[memoize]
+//! fn fib(n: u64) -> u64 {
+//!     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+//! }
+//! # fn main() {
+//! assert_eq!(fib(90), 2880067194370816120);
+//! # }
+//! ```
+
+pub mod memoize;
+pub mod lru;
+
+pub use self::memoize::{memoize, memoize_with, memoize_sync, once};
+pub use self::lru::{LruCache, memoize_lru, memoize_ttl};
+pub use pa_macros::memoize;
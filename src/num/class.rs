@@ -0,0 +1,114 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Num typeclass, a type with arithmetic operators.
+///
+/// Law:
+/// identity: `x + zero() == x` and `x * one() == x`
+pub trait Num: Copy + PartialOrd + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Rem<Output=Self> {
+    /// **Signature**: zero :: T
+    fn zero() -> Self;
+    /// **Signature**: one :: T
+    fn one() -> Self;
+}
+
+/// Signed typeclass, a [`Num`] with negation.
+///
+/// Law:
+/// `x.abs() * x.signum() == x`
+pub trait Signed: Num + Neg<Output=Self> {
+    /// **Signature**: abs :: T -> T
+    fn abs(self) -> Self {
+        if self < Self::zero() { -self } else { self }
+    }
+
+    /// -1 for negative, 1 for positive, zero stays zero
+    ///
+    /// **Signature**: signum :: T -> T
+    fn signum(self) -> Self {
+        if self < Self::zero() {
+            -Self::one()
+        } else if self > Self::zero() {
+            Self::one()
+        } else {
+            self
+        }
+    }
+}
+
+/// Integral typeclass, a [`Num`] with integer division, i.e. `quot`, `rem`, `div` and `mod` in Haskell.
+///
+/// Law:
+/// `q * y + r == x` for both `(q, r) = x.quot_rem(y)` and `(q, r) = x.div_mod(y)`
+pub trait Integral: Num + Ord {
+    /// Remainder which is zero instead of overflowing for `MIN % -1`
+    ///
+    /// **Signature**: wrapping_rem :: T -> T -> T
+    fn wrapping_rem(self, other: Self) -> Self;
+
+    /// Division truncated toward zero, remainder has the sign of `self`
+    ///
+    /// **Signature**: quot_rem :: T -> T -> (T, T)
+    fn quot_rem(self, other: Self) -> (Self, Self) {
+        (self / other, self % other)
+    }
+
+    /// Division floored toward negative infinity, remainder has the sign of `other`
+    ///
+    /// **Signature**: div_mod :: T -> T -> (T, T)
+    fn div_mod(self, other: Self) -> (Self, Self) {
+        let (q, r) = self.quot_rem(other);
+        if r != Self::zero() && (r < Self::zero()) != (other < Self::zero()) {
+            (q - Self::one(), r + other)
+        } else {
+            (q, r)
+        }
+    }
+}
+
+/// Fractional typeclass, a [`Num`] with exact division.
+///
+/// Law:
+/// `x * x.recip() == one()` for `x != zero()`
+pub trait Fractional: Num {
+    /// **Signature**: recip :: T -> T
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+}
+
+macro_rules! impl_num {
+    (integral $($t:ty),*) => {$(
+        impl_num!(0, 1; $t);
+        impl Integral for $t {
+            fn wrapping_rem(self, other: Self) -> Self { <$t>::wrapping_rem(self, other) }
+        }
+    )*};
+    ($zero:expr, $one:expr; $($t:ty),*) => {$(
+        impl Num for $t {
+            fn zero() -> Self { $zero }
+            fn one() -> Self { $one }
+        }
+    )*};
+}
+
+impl_num!(integral i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_num!(0.0, 1.0; f32, f64);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+impl Signed for f32 {}
+impl Signed for f64 {}
+
+impl Fractional for f32 {}
+impl Fractional for f64 {}
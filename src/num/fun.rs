@@ -0,0 +1,112 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::class::{Fractional, Integral, Num, Signed};
+
+/// Absolute value of [`Signed`]
+///
+/// # Arguments
+///
+/// * `x`: [`Signed`] T => T
+pub fn abs<T: Signed>(x: T) -> T {
+    x.abs()
+}
+
+/// Sign of [`Signed`], -1, 0 or 1. Zero of floats stays `0.0` unlike [`f64::signum`]
+///
+/// # Arguments
+///
+/// * `x`: [`Signed`] T => T
+pub fn signum<T: Signed>(x: T) -> T {
+    x.signum()
+}
+
+/// Check if a number is even
+///
+/// # Arguments
+///
+/// * `x`: [`Integral`] T => T
+pub fn even<T: Integral>(x: T) -> bool {
+    x % (T::one() + T::one()) == T::zero()
+}
+
+/// Check if a number is odd, negative numbers included
+///
+/// # Arguments
+///
+/// * `x`: [`Integral`] T => T
+pub fn odd<T: Integral>(x: T) -> bool {
+    !even(x)
+}
+
+/// 1/x
+///
+/// # Arguments
+///
+/// * `x`: [`Fractional`] T => T
+pub fn recip<T: Fractional>(x: T) -> T {
+    x.recip()
+}
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0) == 0`
+///
+/// `gcd(MIN, 0)` and `gcd(MIN, MIN)` of signed integers is `-MIN`, which cannot be represented,
+/// so it overflows like `abs(MIN)`
+///
+/// # Arguments
+///
+/// * `x`: [`Integral`] T => T
+/// * `y`: [`Integral`] T => T
+pub fn gcd<T: Integral>(x: T, y: T) -> T {
+    let (mut a, mut b) = (x, y);
+    while b != T::zero() {
+        let r = a.wrapping_rem(b);
+        a = b;
+        b = r;
+    }
+    if a < T::zero() { T::zero() - a } else { a }
+}
+
+/// Least common multiple, always non-negative. It is zero if any of `x` and `y` is zero.
+/// It overflows if the result cannot be represented, e.g. `lcm(MIN, -1)`
+///
+/// # Arguments
+///
+/// * `x`: [`Integral`] T => T
+/// * `y`: [`Integral`] T => T
+pub fn lcm<T: Integral>(x: T, y: T) -> T {
+    if x == T::zero() || y == T::zero() {
+        return T::zero();
+    }
+    let r = x / gcd(x, y) * y;
+    if r < T::zero() { T::zero() - r } else { r }
+}
+
+/// x to the power of n, by squaring
+///
+/// # Arguments
+///
+/// * `x`: [`Num`] T => T
+/// * `n`: [`Integral`] N => N, exponent
+///
+/// # Panics
+/// if n < 0
+pub fn pow<T: Num, N: Integral>(x: T, n: N) -> T {
+    assert!(n >= N::zero(), "negative exponent");
+    let two = N::one() + N::one();
+    let (mut x, mut n, mut acc) = (x, n, T::one());
+    loop {
+        if odd(n) {
+            acc = acc * x;
+        }
+        n = n / two;
+        if n == N::zero() {
+            return acc;
+        }
+        x = x * x;
+    }
+}
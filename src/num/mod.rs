@@ -0,0 +1,27 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module num provide Haskell-like numeric typeclasses as traits, implemented for all primitive numerics.
+//!
+//! [`Num`] is the base of [`Signed`], [`Integral`] and [`Fractional`], functions are generic over them:
+//!
+//! ```
+//! # extern crate pa;
+//! # use pa::num::*;
+//! # fn main() {
+//! assert!(odd(-3));
+//! assert_eq!(gcd(12, -18), 6);
+//! assert_eq!(pow(1.5, 2), 2.25);
+//! assert_eq!(recip(4.0), 0.25);
+//! # }
+//! ```
+
+pub mod class;
+pub mod fun;
+
+pub use self::class::{Num, Signed, Integral, Fractional};
+pub use self::fun::{abs, signum, even, odd, recip, gcd, lcm, pow};
@@ -0,0 +1,77 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+use super::traversal::Traversal;
+use super::lens::Lens;
+use super::prism::Prism;
+use super::iso::Iso;
+
+/// Composition of two optics, see [`Fold::then`].
+/// It is the weaker one of the two, e.g. [`Lens`] then [`Prism`] is a [`Traversal`].
+#[derive(Debug, Clone, Copy)]
+pub struct Compose<O1, O2> {
+    outer: O1,
+    inner: O2,
+}
+
+impl<O1, O2> Compose<O1, O2> {
+    pub(crate) fn new(outer: O1, inner: O2) -> Self {
+        Compose { outer, inner }
+    }
+}
+
+impl<S, O1: Fold<S>, O2: Fold<O1::Focus>> Fold<S> for Compose<O1, O2> {
+    type Focus = O2::Focus;
+
+    fn to_vec_of(&self, s: &S) -> Vec<O2::Focus> {
+        self.outer.to_vec_of(s).iter().flat_map(|a| self.inner.to_vec_of(a)).collect()
+    }
+
+    fn preview(&self, s: &S) -> Option<O2::Focus> {
+        self.outer.to_vec_of(s).iter().filter_map(|a| self.inner.preview(a)).next()
+    }
+}
+
+impl<S, O1: Traversal<S>, O2: Traversal<O1::Focus>> Traversal<S> for Compose<O1, O2> {
+    fn over(&self, s: S, mut f: impl FnMut(O2::Focus) -> O2::Focus) -> S {
+        self.outer.over(s, |a| self.inner.over(a, &mut f))
+    }
+}
+
+impl<S, O1: Lens<S>, O2: Lens<O1::Focus>> Lens<S> for Compose<O1, O2> {
+    fn get(&self, s: &S) -> O2::Focus {
+        self.inner.get(&self.outer.get(s))
+    }
+
+    fn put(&self, s: S, b: O2::Focus) -> S {
+        let a = self.outer.get(&s);
+        self.outer.put(s, self.inner.put(a, b))
+    }
+}
+
+impl<S, O1: Prism<S>, O2: Prism<O1::Focus>> Prism<S> for Compose<O1, O2> {
+    fn review(&self, b: O2::Focus) -> S {
+        self.outer.review(self.inner.review(b))
+    }
+}
+
+impl<S, O1: Iso<S>, O2: Iso<O1::Focus>> Iso<S> for Compose<O1, O2> {}
+
+/// Compose optics from the outermost to the innermost, same as chaining [`Fold::then`]
+///
+/// Syntax:
+/// lens_path!(o1, o2, o3...) = o1.then(o2).then(o3)...
+#[macro_export] macro_rules! lens_path {
+    ($o:expr) => {$o};
+    ($o:expr, $($os:expr),*) => {{
+        use $crate::optics::Fold;
+        $o$(.then($os))*
+    }};
+}
@@ -0,0 +1,85 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use super::compose::Compose;
+
+/// Fold, the weakest optic, reads any number of focuses of `S`.
+pub trait Fold<S> {
+    /// Type of the focus
+    type Focus;
+
+    /// Collect all focuses
+    ///
+    /// **Signature**: to_vec_of :: Fold S A -> S -> [A]
+    fn to_vec_of(&self, s: &S) -> Vec<Self::Focus>;
+
+    /// Get the first focus, if any
+    ///
+    /// **Signature**: preview :: Fold S A -> S -> Maybe A
+    fn preview(&self, s: &S) -> Option<Self::Focus> {
+        self.to_vec_of(s).into_iter().next()
+    }
+
+    /// Compose with an optic focusing inside the focus of this one
+    ///
+    /// **Signature**: then :: Optic S A -> Optic A B -> Optic S B
+    fn then<O: Fold<Self::Focus>>(self, other: O) -> Compose<Self, O> where Self: Sized {
+        Compose::new(self, other)
+    }
+}
+
+/// [`Fold`] built from a function, see [folding]
+pub struct FnFold<S, A, F> {
+    f: F,
+    marker: PhantomData<fn(&S) -> A>,
+}
+
+impl<S, A, F: Clone> Clone for FnFold<S, A, F> {
+    fn clone(&self) -> Self {
+        FnFold { f: self.f.clone(), marker: PhantomData }
+    }
+}
+
+impl<S, A, F: Fn(&S) -> Vec<A>> Fold<S> for FnFold<S, A, F> {
+    type Focus = A;
+
+    fn to_vec_of(&self, s: &S) -> Vec<A> {
+        (self.f)(s)
+    }
+}
+
+/// Build a [`Fold`] from a function listing all focuses
+///
+/// # Arguments
+///
+/// * `f`: f :: S -> [A]
+pub fn folding<S, A, F: Fn(&S) -> Vec<A>>(f: F) -> FnFold<S, A, F> {
+    FnFold { f, marker: PhantomData }
+}
+
+/// Get the first focus of any optic, `None` if nothing is focused
+///
+/// # Arguments
+///
+/// * `o`: [`Fold`] S A
+/// * `s`: S
+pub fn preview<S, O: Fold<S>>(o: &O, s: &S) -> Option<O::Focus> {
+    o.preview(s)
+}
+
+/// Collect all focuses of any optic
+///
+/// # Arguments
+///
+/// * `o`: [`Fold`] S A
+/// * `s`: S
+pub fn to_vec_of<S, O: Fold<S>>(o: &O, s: &S) -> Vec<O::Focus> {
+    o.to_vec_of(s)
+}
@@ -0,0 +1,184 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use alloc::collections::{VecDeque, BTreeMap};
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+use super::traversal::Traversal;
+use super::lens::Lens;
+
+/// [`Traversal`] focusing the element at a position or key, nothing is focused if it is absent.
+/// See [lens_index]
+#[derive(Debug, Clone, Copy)]
+pub struct Index<K> {
+    key: K,
+}
+
+/// Focus the element at index of `Vec`, `VecDeque`, or value at key of `HashMap` and `BTreeMap`
+///
+/// # Arguments
+///
+/// * `key`: index or key
+pub fn lens_index<K>(key: K) -> Index<K> {
+    Index { key }
+}
+
+impl<T: Clone> Fold<Vec<T>> for Index<usize> {
+    type Focus = T;
+
+    fn to_vec_of(&self, s: &Vec<T>) -> Vec<T> {
+        s.get(self.key).cloned().into_iter().collect()
+    }
+}
+
+impl<T: Clone> Traversal<Vec<T>> for Index<usize> {
+    fn over(&self, mut s: Vec<T>, mut f: impl FnMut(T) -> T) -> Vec<T> {
+        if self.key < s.len() {
+            // move the element out and back without shifting the others
+            let a = s.swap_remove(self.key);
+            s.push(f(a));
+            let last = s.len() - 1;
+            s.swap(self.key, last);
+        }
+        s
+    }
+}
+
+impl<T: Clone> Fold<VecDeque<T>> for Index<usize> {
+    type Focus = T;
+
+    fn to_vec_of(&self, s: &VecDeque<T>) -> Vec<T> {
+        s.get(self.key).cloned().into_iter().collect()
+    }
+}
+
+impl<T: Clone> Traversal<VecDeque<T>> for Index<usize> {
+    fn over(&self, mut s: VecDeque<T>, mut f: impl FnMut(T) -> T) -> VecDeque<T> {
+        if let Some(a) = s.remove(self.key) {
+            s.insert(self.key, f(a));
+        }
+        s
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V: Clone> Fold<HashMap<K, V>> for Index<K> {
+    type Focus = V;
+
+    fn to_vec_of(&self, s: &HashMap<K, V>) -> Vec<V> {
+        s.get(&self.key).cloned().into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq + Clone, V: Clone> Traversal<HashMap<K, V>> for Index<K> {
+    fn over(&self, mut s: HashMap<K, V>, mut f: impl FnMut(V) -> V) -> HashMap<K, V> {
+        if let Some(v) = s.remove(&self.key) {
+            s.insert(self.key.clone(), f(v));
+        }
+        s
+    }
+}
+
+impl<K: Ord, V: Clone> Fold<BTreeMap<K, V>> for Index<K> {
+    type Focus = V;
+
+    fn to_vec_of(&self, s: &BTreeMap<K, V>) -> Vec<V> {
+        s.get(&self.key).cloned().into_iter().collect()
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Traversal<BTreeMap<K, V>> for Index<K> {
+    fn over(&self, mut s: BTreeMap<K, V>, mut f: impl FnMut(V) -> V) -> BTreeMap<K, V> {
+        if let Some(v) = s.remove(&self.key) {
+            s.insert(self.key.clone(), f(v));
+        }
+        s
+    }
+}
+
+/// [`Lens`] focusing the entry of a map as [`Option`], putting `None` removes the key.
+/// See [at]
+#[derive(Debug, Clone, Copy)]
+pub struct At<K> {
+    key: K,
+}
+
+/// Focus the entry at key of `HashMap` and `BTreeMap`
+///
+/// # Arguments
+///
+/// * `key`: key of the entry
+pub fn at<K>(key: K) -> At<K> {
+    At { key }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq + Clone, V: Clone> Fold<HashMap<K, V>> for At<K> {
+    type Focus = Option<V>;
+
+    fn to_vec_of(&self, s: &HashMap<K, V>) -> Vec<Option<V>> {
+        vec![self.get(s)]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq + Clone, V: Clone> Traversal<HashMap<K, V>> for At<K> {
+    fn over(&self, s: HashMap<K, V>, mut f: impl FnMut(Option<V>) -> Option<V>) -> HashMap<K, V> {
+        let a = self.get(&s);
+        self.put(s, f(a))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq + Clone, V: Clone> Lens<HashMap<K, V>> for At<K> {
+    fn get(&self, s: &HashMap<K, V>) -> Option<V> {
+        s.get(&self.key).cloned()
+    }
+
+    fn put(&self, mut s: HashMap<K, V>, a: Option<V>) -> HashMap<K, V> {
+        match a {
+            Some(v) => s.insert(self.key.clone(), v),
+            None => s.remove(&self.key),
+        };
+        s
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Fold<BTreeMap<K, V>> for At<K> {
+    type Focus = Option<V>;
+
+    fn to_vec_of(&self, s: &BTreeMap<K, V>) -> Vec<Option<V>> {
+        vec![self.get(s)]
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Traversal<BTreeMap<K, V>> for At<K> {
+    fn over(&self, s: BTreeMap<K, V>, mut f: impl FnMut(Option<V>) -> Option<V>) -> BTreeMap<K, V> {
+        let a = self.get(&s);
+        self.put(s, f(a))
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Lens<BTreeMap<K, V>> for At<K> {
+    fn get(&self, s: &BTreeMap<K, V>) -> Option<V> {
+        s.get(&self.key).cloned()
+    }
+
+    fn put(&self, mut s: BTreeMap<K, V>, a: Option<V>) -> BTreeMap<K, V> {
+        match a {
+            Some(v) => s.insert(self.key.clone(), v),
+            None => s.remove(&self.key),
+        };
+        s
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+use super::traversal::Traversal;
+use super::lens::Lens;
+use super::prism::Prism;
+
+/// Iso, a lossless conversion between `S` and its focus, which is both [`Lens`] and [`Prism`].
+pub trait Iso<S>: Lens<S> + Prism<S> {}
+
+/// [`Iso`] built from a pair of conversions, see [iso]
+pub struct FnIso<S, A, T, F> {
+    to: T,
+    from: F,
+    marker: PhantomData<fn(S) -> A>,
+}
+
+impl<S, A, T: Clone, F: Clone> Clone for FnIso<S, A, T, F> {
+    fn clone(&self) -> Self {
+        FnIso { to: self.to.clone(), from: self.from.clone(), marker: PhantomData }
+    }
+}
+
+impl<S, A, T: Fn(&S) -> A, F: Fn(A) -> S> Fold<S> for FnIso<S, A, T, F> {
+    type Focus = A;
+
+    fn to_vec_of(&self, s: &S) -> Vec<A> {
+        vec![(self.to)(s)]
+    }
+
+    fn preview(&self, s: &S) -> Option<A> {
+        Some((self.to)(s))
+    }
+}
+
+impl<S, A, T: Fn(&S) -> A, F: Fn(A) -> S> Traversal<S> for FnIso<S, A, T, F> {
+    fn over(&self, s: S, mut f: impl FnMut(A) -> A) -> S {
+        (self.from)(f((self.to)(&s)))
+    }
+}
+
+impl<S, A, T: Fn(&S) -> A, F: Fn(A) -> S> Lens<S> for FnIso<S, A, T, F> {
+    fn get(&self, s: &S) -> A {
+        (self.to)(s)
+    }
+
+    fn put(&self, _: S, a: A) -> S {
+        (self.from)(a)
+    }
+}
+
+impl<S, A, T: Fn(&S) -> A, F: Fn(A) -> S> Prism<S> for FnIso<S, A, T, F> {
+    fn review(&self, a: A) -> S {
+        (self.from)(a)
+    }
+}
+
+impl<S, A, T: Fn(&S) -> A, F: Fn(A) -> S> Iso<S> for FnIso<S, A, T, F> {}
+
+/// Build an [`Iso`] from a pair of conversions, `from(to(s))` must equal to `s`
+///
+/// # Arguments
+///
+/// * `to`: to :: S -> A
+/// * `from`: from :: A -> S
+pub fn iso<S, A, T, F>(to: T, from: F) -> FnIso<S, A, T, F>
+    where T: Fn(&S) -> A, F: Fn(A) -> S
+{
+    FnIso { to, from, marker: PhantomData }
+}
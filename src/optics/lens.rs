@@ -0,0 +1,92 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+use super::traversal::Traversal;
+
+/// Lens, a [`Traversal`] focusing exactly one part of `S`.
+pub trait Lens<S>: Traversal<S> {
+    /// Get the focus
+    ///
+    /// **Signature**: get :: Lens S A -> S -> A
+    fn get(&self, s: &S) -> Self::Focus;
+
+    /// Replace the focus, unlike [`Traversal::set`] the focus need not be [`Clone`]
+    ///
+    /// **Signature**: put :: Lens S A -> S -> A -> S
+    fn put(&self, s: S, a: Self::Focus) -> S;
+}
+
+/// [`Lens`] built from getter and setter, see [lens]
+pub struct FnLens<S, A, G, St> {
+    get: G,
+    set: St,
+    marker: PhantomData<fn(S) -> A>,
+}
+
+/// [`Lens`] with nameable type, generated by `#[derive(Lenses)]`
+pub type FieldLens<S, A> = FnLens<S, A, fn(&S) -> A, fn(S, A) -> S>;
+
+impl<S, A, G: Clone, St: Clone> Clone for FnLens<S, A, G, St> {
+    fn clone(&self) -> Self {
+        FnLens { get: self.get.clone(), set: self.set.clone(), marker: PhantomData }
+    }
+}
+
+impl<S, A, G: Fn(&S) -> A, St: Fn(S, A) -> S> Fold<S> for FnLens<S, A, G, St> {
+    type Focus = A;
+
+    fn to_vec_of(&self, s: &S) -> Vec<A> {
+        vec![(self.get)(s)]
+    }
+
+    fn preview(&self, s: &S) -> Option<A> {
+        Some((self.get)(s))
+    }
+}
+
+impl<S, A, G: Fn(&S) -> A, St: Fn(S, A) -> S> Traversal<S> for FnLens<S, A, G, St> {
+    fn over(&self, s: S, mut f: impl FnMut(A) -> A) -> S {
+        let a = (self.get)(&s);
+        (self.set)(s, f(a))
+    }
+}
+
+impl<S, A, G: Fn(&S) -> A, St: Fn(S, A) -> S> Lens<S> for FnLens<S, A, G, St> {
+    fn get(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+
+    fn put(&self, s: S, a: A) -> S {
+        (self.set)(s, a)
+    }
+}
+
+/// Build a [`Lens`] from getter and setter
+///
+/// # Arguments
+///
+/// * `get`: get :: S -> A
+/// * `set`: set :: (S, A) -> S
+pub fn lens<S, A, G, St>(get: G, set: St) -> FnLens<S, A, G, St>
+    where G: Fn(&S) -> A, St: Fn(S, A) -> S
+{
+    FnLens { get, set, marker: PhantomData }
+}
+
+/// Get the focus of a [`Lens`]
+///
+/// # Arguments
+///
+/// * `l`: [`Lens`] S A
+/// * `s`: S
+pub fn view<S, L: Lens<S>>(l: &L, s: &S) -> L::Focus {
+    l.get(s)
+}
@@ -0,0 +1,32 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module optics provide composable accessors of immutable data, as Ramda `lens`, `view`, `set` and `over`.
+//!
+//! Optics form a hierarchy of traits, from the weakest to the strongest:
+//! [`Fold`] reads any number of focuses, [`Traversal`] modifies them,
+//! [`Lens`] focuses exactly one, [`Prism`] focuses at most one and can build the whole back,
+//! [`Iso`] is both. Composing two optics by [`then`](Fold::then) gives the weaker one of them.
+//!
+//! Field lenses of a struct can be generated by `#[derive(Lenses)]`.
+
+pub mod fold;
+pub mod traversal;
+pub mod lens;
+pub mod prism;
+pub mod iso;
+pub mod compose;
+pub mod index;
+
+pub use self::fold::{Fold, FnFold, folding, preview, to_vec_of};
+pub use self::traversal::{Traversal, Each, each, set, over};
+pub use self::lens::{Lens, FnLens, FieldLens, lens, view};
+pub use self::prism::{Prism, FnPrism, VariantPrism, prism, some, ok, err, review};
+pub use self::iso::{Iso, FnIso, iso};
+pub use self::compose::Compose;
+pub use self::index::{Index, At, lens_index, at};
+pub use pa_macros::Lenses;
@@ -0,0 +1,101 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+use super::traversal::Traversal;
+
+/// Prism, a [`Traversal`] focusing at most one part of `S`, e.g. a variant of enum.
+/// The whole can be built back from the focus.
+pub trait Prism<S>: Traversal<S> {
+    /// Build the whole from a focus
+    ///
+    /// **Signature**: review :: Prism S A -> A -> S
+    fn review(&self, a: Self::Focus) -> S;
+}
+
+/// [`Prism`] built from matcher and builder, see [prism]
+pub struct FnPrism<S, A, P, R> {
+    preview: P,
+    review: R,
+    marker: PhantomData<fn(S) -> A>,
+}
+
+/// [`Prism`] with nameable type, e.g. [some], [ok] and [err]
+pub type VariantPrism<S, A> = FnPrism<S, A, fn(&S) -> Option<A>, fn(A) -> S>;
+
+impl<S, A, P: Clone, R: Clone> Clone for FnPrism<S, A, P, R> {
+    fn clone(&self) -> Self {
+        FnPrism { preview: self.preview.clone(), review: self.review.clone(), marker: PhantomData }
+    }
+}
+
+impl<S, A, P: Fn(&S) -> Option<A>, R: Fn(A) -> S> Fold<S> for FnPrism<S, A, P, R> {
+    type Focus = A;
+
+    fn to_vec_of(&self, s: &S) -> Vec<A> {
+        (self.preview)(s).into_iter().collect()
+    }
+
+    fn preview(&self, s: &S) -> Option<A> {
+        (self.preview)(s)
+    }
+}
+
+impl<S, A, P: Fn(&S) -> Option<A>, R: Fn(A) -> S> Traversal<S> for FnPrism<S, A, P, R> {
+    fn over(&self, s: S, mut f: impl FnMut(A) -> A) -> S {
+        match (self.preview)(&s) {
+            Some(a) => (self.review)(f(a)),
+            None => s,
+        }
+    }
+}
+
+impl<S, A, P: Fn(&S) -> Option<A>, R: Fn(A) -> S> Prism<S> for FnPrism<S, A, P, R> {
+    fn review(&self, a: A) -> S {
+        (self.review)(a)
+    }
+}
+
+/// Build a [`Prism`] from matcher and builder
+///
+/// # Arguments
+///
+/// * `preview`: preview :: S -> Maybe A
+/// * `review`: review :: A -> S
+pub fn prism<S, A, P, R>(preview: P, review: R) -> FnPrism<S, A, P, R>
+    where P: Fn(&S) -> Option<A>, R: Fn(A) -> S
+{
+    FnPrism { preview, review, marker: PhantomData }
+}
+
+/// [`Prism`] focusing `Some` of [`Option`]
+pub fn some<T: Clone>() -> VariantPrism<Option<T>, T> {
+    FnPrism { preview: Option::clone, review: Some, marker: PhantomData }
+}
+
+/// [`Prism`] focusing `Ok` of [`Result`]
+pub fn ok<T: Clone, E>() -> VariantPrism<Result<T, E>, T> {
+    FnPrism { preview: |r| r.as_ref().ok().cloned(), review: Ok, marker: PhantomData }
+}
+
+/// [`Prism`] focusing `Err` of [`Result`]
+pub fn err<T, E: Clone>() -> VariantPrism<Result<T, E>, E> {
+    FnPrism { preview: |r| r.as_ref().err().cloned(), review: Err, marker: PhantomData }
+}
+
+/// Build the whole from a focus by a [`Prism`]
+///
+/// # Arguments
+///
+/// * `p`: [`Prism`] S A
+/// * `a`: A
+pub fn review<S, P: Prism<S>>(p: &P, a: P::Focus) -> S {
+    p.review(a)
+}
@@ -0,0 +1,133 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use alloc::collections::{VecDeque, BTreeMap};
+use alloc::vec::Vec;
+
+use super::fold::Fold;
+
+/// Traversal, a [`Fold`] which can also modify all its focuses.
+pub trait Traversal<S>: Fold<S> {
+    /// Modify every focus by a function
+    ///
+    /// **Signature**: over :: Traversal S A -> S -> (A -> A) -> S
+    fn over(&self, s: S, f: impl FnMut(Self::Focus) -> Self::Focus) -> S;
+
+    /// Replace every focus by a value
+    ///
+    /// **Signature**: set :: Traversal S A -> S -> A -> S
+    fn set(&self, s: S, a: Self::Focus) -> S where Self::Focus: Clone {
+        self.over(s, |_| a.clone())
+    }
+}
+
+/// [`Traversal`] over every element of a container, see [each]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Each;
+
+/// Focus every element of `Vec`, `VecDeque`, `Option`, or every value of `HashMap` and `BTreeMap`
+pub fn each() -> Each {
+    Each
+}
+
+impl<T: Clone> Fold<Vec<T>> for Each {
+    type Focus = T;
+
+    fn to_vec_of(&self, s: &Vec<T>) -> Vec<T> {
+        s.clone()
+    }
+}
+
+impl<T: Clone> Traversal<Vec<T>> for Each {
+    fn over(&self, s: Vec<T>, f: impl FnMut(T) -> T) -> Vec<T> {
+        s.into_iter().map(f).collect()
+    }
+}
+
+impl<T: Clone> Fold<VecDeque<T>> for Each {
+    type Focus = T;
+
+    fn to_vec_of(&self, s: &VecDeque<T>) -> Vec<T> {
+        s.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone> Traversal<VecDeque<T>> for Each {
+    fn over(&self, s: VecDeque<T>, f: impl FnMut(T) -> T) -> VecDeque<T> {
+        s.into_iter().map(f).collect()
+    }
+}
+
+impl<T: Clone> Fold<Option<T>> for Each {
+    type Focus = T;
+
+    fn to_vec_of(&self, s: &Option<T>) -> Vec<T> {
+        s.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone> Traversal<Option<T>> for Each {
+    fn over(&self, s: Option<T>, f: impl FnMut(T) -> T) -> Option<T> {
+        s.map(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V: Clone> Fold<HashMap<K, V>> for Each {
+    type Focus = V;
+
+    fn to_vec_of(&self, s: &HashMap<K, V>) -> Vec<V> {
+        s.values().cloned().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V: Clone> Traversal<HashMap<K, V>> for Each {
+    fn over(&self, s: HashMap<K, V>, mut f: impl FnMut(V) -> V) -> HashMap<K, V> {
+        s.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+impl<K: Ord, V: Clone> Fold<BTreeMap<K, V>> for Each {
+    type Focus = V;
+
+    fn to_vec_of(&self, s: &BTreeMap<K, V>) -> Vec<V> {
+        s.values().cloned().collect()
+    }
+}
+
+impl<K: Ord, V: Clone> Traversal<BTreeMap<K, V>> for Each {
+    fn over(&self, s: BTreeMap<K, V>, mut f: impl FnMut(V) -> V) -> BTreeMap<K, V> {
+        s.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+}
+
+/// Replace every focus of a [`Traversal`] by a value
+///
+/// # Arguments
+///
+/// * `t`: [`Traversal`] S A
+/// * `a`: A, new value
+/// * `s`: S
+pub fn set<S, T: Traversal<S>>(t: &T, a: T::Focus, s: S) -> S where T::Focus: Clone {
+    t.set(s, a)
+}
+
+/// Modify every focus of a [`Traversal`] by a function
+///
+/// # Arguments
+///
+/// * `t`: [`Traversal`] S A
+/// * `f`: f :: A -> A
+/// * `s`: S
+pub fn over<S, T: Traversal<S>>(t: &T, f: impl FnMut(T::Focus) -> T::Focus, s: S) -> S {
+    t.over(s, f)
+}
@@ -0,0 +1,311 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::slice;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+const BITS: u32 = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+enum Node<K, V> {
+    /// Children are indexed by 5 bits of hash at each level, only present ones are stored
+    Branch { bitmap: u32, children: Vec<Arc<Node<K, V>>> },
+    /// Entries with same hash
+    Leaf { hash: u64, entries: Vec<Arc<(K, V)>> },
+}
+
+/// Persistent hash map based on hash array mapped trie.
+///
+/// [get](HashMap::get), [insert](HashMap::insert) and [remove](HashMap::remove) are O(log<sub>32</sub> n).
+pub struct HashMap<K, V> {
+    root: Arc<Node<K, V>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> HashMap<K, V> {
+    /// Empty map
+    pub fn new() -> Self {
+        HashMap { root: Arc::new(Node::Branch { bitmap: 0, children: Vec::new() }), len: 0, hasher: RandomState::new() }
+    }
+
+    /// Count of entries, O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there is no entry
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Getting the value of key
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Hash`]+[`Eq`] K => &K
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let hash = self.hash(key);
+        let mut node = &*self.root;
+        let mut shift = 0;
+        loop {
+            match *node {
+                Node::Branch { bitmap, ref children } => {
+                    let bit = bit(hash, shift);
+                    if bitmap & bit == 0 {
+                        return None;
+                    }
+                    node = &children[index(bitmap, bit)];
+                    shift += BITS;
+                }
+                Node::Leaf { hash: h, ref entries } => {
+                    if h != hash {
+                        return None;
+                    }
+                    return entries.iter().find(|e| e.0.borrow() == key).map(|e| &e.1);
+                }
+            }
+        }
+    }
+
+    /// Check if key exists
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Hash`]+[`Eq`] K => &K
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool where K: Borrow<Q> {
+        self.get(key).is_some()
+    }
+
+    /// Insert an entry, the value is replaced if key exists
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Hash`]+[`Eq`] K => K
+    /// * `value`: V
+    pub fn insert(&self, key: K, value: V) -> Self {
+        let hash = self.hash(&key);
+        let (root, added) = insert(&self.root, 0, hash, Arc::new((key, value)));
+        HashMap { root, len: if added { self.len + 1 } else { self.len }, hasher: self.hasher.clone() }
+    }
+
+    /// Remove an entry, a clone of this map is returned if key does not exist
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Hash`]+[`Eq`] K => &K
+    pub fn remove<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Self where K: Borrow<Q> {
+        let hash = self.hash(key);
+        match remove(&self.root, 0, hash, key) {
+            None => self.clone(),
+            Some(root) => HashMap {
+                root: root.unwrap_or_else(|| Arc::new(Node::Branch { bitmap: 0, children: Vec::new() })),
+                len: self.len - 1,
+                hasher: self.hasher.clone(),
+            },
+        }
+    }
+
+    fn hash<Q: ?Sized + Hash>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+}
+
+impl<K, V> HashMap<K, V> {
+    /// [`Iterator`] over entries in arbitrary order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { stack: vec![slice::from_ref(&self.root).iter()], entries: [].iter(), len: self.len }
+    }
+
+    /// [`Iterator`] over keys in arbitrary order
+    pub fn keys(&self) -> impl Iterator<Item=&K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// [`Iterator`] over values in arbitrary order
+    pub fn values(&self) -> impl Iterator<Item=&V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+fn bit(hash: u64, shift: u32) -> u32 {
+    1 << ((hash >> shift) & MASK)
+}
+
+fn index(bitmap: u32, bit: u32) -> usize {
+    (bitmap & (bit - 1)).count_ones() as usize
+}
+
+/// Return the new node and whether a new key is added
+fn insert<K: Eq, V>(node: &Arc<Node<K, V>>, shift: u32, hash: u64, entry: Arc<(K, V)>) -> (Arc<Node<K, V>>, bool) {
+    match **node {
+        Node::Branch { bitmap, ref children } => {
+            let bit = bit(hash, shift);
+            let i = index(bitmap, bit);
+            let mut children = children.clone();
+            let added = if bitmap & bit == 0 {
+                children.insert(i, Arc::new(Node::Leaf { hash, entries: vec![entry] }));
+                true
+            } else {
+                let (child, added) = insert(&children[i], shift + BITS, hash, entry);
+                children[i] = child;
+                added
+            };
+            (Arc::new(Node::Branch { bitmap: bitmap | bit, children }), added)
+        }
+        Node::Leaf { hash: h, ref entries } if h == hash => {
+            let mut entries = entries.clone();
+            let added = match entries.iter().position(|e| e.0 == entry.0) {
+                Some(i) => {
+                    entries[i] = entry;
+                    false
+                }
+                None => {
+                    entries.push(entry);
+                    true
+                }
+            };
+            (Arc::new(Node::Leaf { hash, entries }), added)
+        }
+        Node::Leaf { hash: h, .. } => {
+            // hashes are different, they must be split at some level
+            let branch = Arc::new(Node::Branch { bitmap: bit(h, shift), children: vec![node.clone()] });
+            insert(&branch, shift, hash, entry)
+        }
+    }
+}
+
+/// Return `None` if key does not exist, `Some(None)` if the node becomes empty
+fn remove<K, V, Q>(node: &Node<K, V>, shift: u32, hash: u64, key: &Q) -> Option<Option<Arc<Node<K, V>>>>
+    where K: Borrow<Q>, Q: ?Sized + Eq
+{
+    match *node {
+        Node::Branch { bitmap, ref children } => {
+            let bit = bit(hash, shift);
+            if bitmap & bit == 0 {
+                return None;
+            }
+            let i = index(bitmap, bit);
+            let child = remove(&children[i], shift + BITS, hash, key)?;
+            let mut children = children.clone();
+            let mut bitmap = bitmap;
+            match child {
+                Some(child) => children[i] = child,
+                None => {
+                    children.remove(i);
+                    bitmap &= !bit;
+                }
+            }
+            // a single leaf needs no branch
+            if children.len() == 1 {
+                if let Node::Leaf { .. } = *children[0] {
+                    return Some(Some(children.pop().unwrap()));
+                }
+            }
+            if children.is_empty() {
+                return Some(None);
+            }
+            Some(Some(Arc::new(Node::Branch { bitmap, children })))
+        }
+        Node::Leaf { hash: h, ref entries } => {
+            if h != hash {
+                return None;
+            }
+            let i = entries.iter().position(|e| e.0.borrow() == key)?;
+            let mut entries = entries.clone();
+            entries.remove(i);
+            if entries.is_empty() {
+                return Some(None);
+            }
+            Some(Some(Arc::new(Node::Leaf { hash, entries })))
+        }
+    }
+}
+
+impl<K, V> Clone for HashMap<K, V> {
+    fn clone(&self) -> Self {
+        HashMap { root: self.root.clone(), len: self.len, hasher: self.hasher.clone() }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for HashMap<K, V> {
+    fn default() -> Self {
+        HashMap::new()
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq> PartialEq for HashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq> Eq for HashMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for HashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for HashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item=(K, V)>>(iter: I) -> Self {
+        iter.into_iter().fold(HashMap::new(), |acc, (k, v)| acc.insert(k, v))
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a HashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// [`Iterator`] of [`HashMap`]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    stack: Vec<slice::Iter<'a, Arc<Node<K, V>>>>,
+    entries: slice::Iter<'a, Arc<(K, V)>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some(entry) = self.entries.next() {
+                self.len -= 1;
+                return Some((&entry.0, &entry.1));
+            }
+            let node = match self.stack.last_mut()?.next() {
+                Some(node) => node,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            match **node {
+                Node::Branch { ref children, .. } => self.stack.push(children.iter()),
+                Node::Leaf { ref entries, .. } => self.entries = entries.iter(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
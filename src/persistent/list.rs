@@ -0,0 +1,153 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::iter::FromIterator;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+struct Node<T> {
+    value: T,
+    next: Option<Arc<Node<T>>>,
+}
+
+/// Persistent singly linked list, i.e. cons list in Haskell.
+///
+/// [cons](List::cons), [head](List::head) and [tail](List::tail) are O(1), the tail is shared.
+pub struct List<T> {
+    head: Option<Arc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    /// Empty list
+    pub fn new() -> Self {
+        List { head: None, len: 0 }
+    }
+
+    /// Prepend an element, i.e. `x : xs` in Haskell
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn cons(&self, x: T) -> Self {
+        List {
+            head: Some(Arc::new(Node { value: x, next: self.head.clone() })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Getting the first element
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Getting all elements except first, `None` if empty
+    pub fn tail(&self) -> Option<Self> {
+        self.head.as_ref().map(|node| List { head: node.next.clone(), len: self.len - 1 })
+    }
+
+    /// Count of elements, O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there is no element
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// [`Iterator`] over references of elements from the head
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), len: self.len }
+    }
+
+    /// Reverse into a new list, elements are cloned
+    pub fn reverse(&self) -> Self where T: Clone {
+        self.iter().fold(List::new(), |acc, x| acc.cons(x.clone()))
+    }
+}
+
+/// Drop nodes one by one, a long list would overflow the stack by recursive drop otherwise
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            match Arc::try_unwrap(node) {
+                Ok(mut node) => next = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List { head: self.head.clone(), len: self.len }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Keep the order of [`Iterator`], i.e. the first item becomes [head](List::head)
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        items.into_iter().rev().fold(List::new(), |acc, x| acc.cons(x))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// [`Iterator`] of [`List`]
+pub struct Iter<'a, T: 'a> {
+    next: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
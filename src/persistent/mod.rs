@@ -0,0 +1,23 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module persistent provide immutable data structures with structural sharing.
+//!
+//! Every update returns a new version and keeps the old one untouched,
+//! unchanged parts are shared by [`Arc`](std::sync::Arc) so cloning is cheap and versions can be sent across threads.
+
+pub mod list;
+pub mod vector;
+#[cfg(feature = "std")]
+pub mod hash_map;
+pub mod red_black_map;
+
+pub use self::list::List;
+pub use self::vector::Vector;
+#[cfg(feature = "std")]
+pub use self::hash_map::HashMap;
+pub use self::red_black_map::RedBlackMap;
@@ -0,0 +1,376 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use self::Color::{Red, Black};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    Red,
+    Black,
+}
+
+type Entry<K, V> = Arc<(K, V)>;
+type Tree<K, V> = Option<Arc<Node<K, V>>>;
+
+struct Node<K, V> {
+    color: Color,
+    left: Tree<K, V>,
+    entry: Entry<K, V>,
+    right: Tree<K, V>,
+}
+
+/// Persistent ordered map based on red black tree.
+///
+/// [get](RedBlackMap::get), [insert](RedBlackMap::insert) and [remove](RedBlackMap::remove) are O(log n),
+/// the insertion is from Okasaki and the deletion is from Kahrs.
+pub struct RedBlackMap<K, V> {
+    root: Tree<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> RedBlackMap<K, V> {
+    /// Empty map
+    pub fn new() -> Self {
+        RedBlackMap { root: None, len: 0 }
+    }
+
+    /// Count of entries, O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there is no entry
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Getting the value of key
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Ord`] K => &K
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        let mut tree = &self.root;
+        while let Some(ref node) = *tree {
+            match key.cmp(node.entry.0.borrow()) {
+                Ordering::Less => tree = &node.left,
+                Ordering::Greater => tree = &node.right,
+                Ordering::Equal => return Some(&node.entry.1),
+            }
+        }
+        None
+    }
+
+    /// Check if key exists
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Ord`] K => &K
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool where K: Borrow<Q> {
+        self.get(key).is_some()
+    }
+
+    /// Insert an entry, the value is replaced if key exists
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Ord`] K => K
+    /// * `value`: V
+    pub fn insert(&self, key: K, value: V) -> Self {
+        let added = !self.contains_key(&key);
+        RedBlackMap {
+            root: blacken(ins(&self.root, Arc::new((key, value)))),
+            len: if added { self.len + 1 } else { self.len },
+        }
+    }
+
+    /// Remove an entry, a clone of this map is returned if key does not exist
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [`Ord`] K => &K
+    pub fn remove<Q: ?Sized + Ord>(&self, key: &Q) -> Self where K: Borrow<Q> {
+        if !self.contains_key(key) {
+            return self.clone();
+        }
+        RedBlackMap { root: blacken(del(&self.root, key)), len: self.len - 1 }
+    }
+}
+
+impl<K, V> RedBlackMap<K, V> {
+    /// [`Iterator`] over entries in ascending order of keys
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter { stack: Vec::new(), len: self.len };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// [`Iterator`] over keys in ascending order
+    pub fn keys(&self) -> impl Iterator<Item=&K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// [`Iterator`] over values in ascending order of keys
+    pub fn values(&self) -> impl Iterator<Item=&V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Entry with minimum key
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(ref left) = node.left {
+            node = left;
+        }
+        Some((&node.entry.0, &node.entry.1))
+    }
+
+    /// Entry with maximum key
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(ref right) = node.right {
+            node = right;
+        }
+        Some((&node.entry.0, &node.entry.1))
+    }
+}
+
+fn node<K, V>(color: Color, left: Tree<K, V>, entry: Entry<K, V>, right: Tree<K, V>) -> Tree<K, V> {
+    Some(Arc::new(Node { color, left, entry, right }))
+}
+
+fn paint<K, V>(color: Color, n: &Node<K, V>) -> Tree<K, V> {
+    node(color, n.left.clone(), n.entry.clone(), n.right.clone())
+}
+
+fn red<K, V>(tree: &Tree<K, V>) -> Option<&Node<K, V>> {
+    tree.as_deref().filter(|n| n.color == Red)
+}
+
+fn black<K, V>(tree: &Tree<K, V>) -> Option<&Node<K, V>> {
+    tree.as_deref().filter(|n| n.color == Black)
+}
+
+fn blacken<K, V>(tree: Tree<K, V>) -> Tree<K, V> {
+    match red(&tree) {
+        Some(n) => paint(Black, n),
+        None => tree,
+    }
+}
+
+fn balance<K, V>(l: Tree<K, V>, e: Entry<K, V>, r: Tree<K, V>) -> Tree<K, V> {
+    if let (Some(ln), Some(rn)) = (red(&l), red(&r)) {
+        return node(Red, paint(Black, ln), e, paint(Black, rn));
+    }
+    if let Some(ln) = red(&l) {
+        if let Some(lln) = red(&ln.left) {
+            return node(Red, paint(Black, lln), ln.entry.clone(), node(Black, ln.right.clone(), e, r));
+        }
+        if let Some(lrn) = red(&ln.right) {
+            return node(Red,
+                        node(Black, ln.left.clone(), ln.entry.clone(), lrn.left.clone()),
+                        lrn.entry.clone(),
+                        node(Black, lrn.right.clone(), e, r));
+        }
+    }
+    if let Some(rn) = red(&r) {
+        if let Some(rrn) = red(&rn.right) {
+            return node(Red, node(Black, l, e, rn.left.clone()), rn.entry.clone(), paint(Black, rrn));
+        }
+        if let Some(rln) = red(&rn.left) {
+            return node(Red,
+                        node(Black, l, e, rln.left.clone()),
+                        rln.entry.clone(),
+                        node(Black, rln.right.clone(), rn.entry.clone(), rn.right.clone()));
+        }
+    }
+    node(Black, l, e, r)
+}
+
+fn ins<K: Ord, V>(tree: &Tree<K, V>, e: Entry<K, V>) -> Tree<K, V> {
+    let n = match *tree {
+        None => return node(Red, None, e, None),
+        Some(ref n) => n,
+    };
+    match (e.0.cmp(&n.entry.0), n.color) {
+        (Ordering::Less, Black) => balance(ins(&n.left, e), n.entry.clone(), n.right.clone()),
+        (Ordering::Less, Red) => node(Red, ins(&n.left, e), n.entry.clone(), n.right.clone()),
+        (Ordering::Greater, Black) => balance(n.left.clone(), n.entry.clone(), ins(&n.right, e)),
+        (Ordering::Greater, Red) => node(Red, n.left.clone(), n.entry.clone(), ins(&n.right, e)),
+        (Ordering::Equal, color) => node(color, n.left.clone(), e, n.right.clone()),
+    }
+}
+
+fn del<K, V, Q>(tree: &Tree<K, V>, key: &Q) -> Tree<K, V> where K: Borrow<Q>, Q: ?Sized + Ord {
+    let n = tree.as_ref()?;
+    match key.cmp(n.entry.0.borrow()) {
+        Ordering::Less => if black(&n.left).is_some() {
+            bal_left(del(&n.left, key), n.entry.clone(), n.right.clone())
+        } else {
+            node(Red, del(&n.left, key), n.entry.clone(), n.right.clone())
+        },
+        Ordering::Greater => if black(&n.right).is_some() {
+            bal_right(n.left.clone(), n.entry.clone(), del(&n.right, key))
+        } else {
+            node(Red, n.left.clone(), n.entry.clone(), del(&n.right, key))
+        },
+        Ordering::Equal => fuse(&n.left, &n.right),
+    }
+}
+
+/// Black height of left tree is one less than right tree
+fn bal_left<K, V>(l: Tree<K, V>, e: Entry<K, V>, r: Tree<K, V>) -> Tree<K, V> {
+    if let Some(ln) = red(&l) {
+        return node(Red, paint(Black, ln), e, r);
+    }
+    if let Some(rn) = black(&r) {
+        return balance(l, e, paint(Red, rn));
+    }
+    match red(&r).and_then(|rn| black(&rn.left).map(|rln| (rn, rln))) {
+        Some((rn, rln)) => node(Red,
+                                node(Black, l, e, rln.left.clone()),
+                                rln.entry.clone(),
+                                balance(rln.right.clone(), rn.entry.clone(), redden(&rn.right))),
+        None => unreachable!("invariant of red black tree is violated"),
+    }
+}
+
+/// Black height of right tree is one less than left tree
+fn bal_right<K, V>(l: Tree<K, V>, e: Entry<K, V>, r: Tree<K, V>) -> Tree<K, V> {
+    if let Some(rn) = red(&r) {
+        return node(Red, l, e, paint(Black, rn));
+    }
+    if let Some(ln) = black(&l) {
+        return balance(paint(Red, ln), e, r);
+    }
+    match red(&l).and_then(|ln| black(&ln.right).map(|lrn| (ln, lrn))) {
+        Some((ln, lrn)) => node(Red,
+                                balance(redden(&ln.left), ln.entry.clone(), lrn.left.clone()),
+                                lrn.entry.clone(),
+                                node(Black, lrn.right.clone(), e, r)),
+        None => unreachable!("invariant of red black tree is violated"),
+    }
+}
+
+fn redden<K, V>(tree: &Tree<K, V>) -> Tree<K, V> {
+    match black(tree) {
+        Some(n) => paint(Red, n),
+        None => unreachable!("invariant of red black tree is violated"),
+    }
+}
+
+/// Join two trees with same black height, all keys of left tree are less than right tree
+fn fuse<K, V>(l: &Tree<K, V>, r: &Tree<K, V>) -> Tree<K, V> {
+    let (ln, rn) = match (l.as_ref(), r.as_ref()) {
+        (None, _) => return r.clone(),
+        (_, None) => return l.clone(),
+        (Some(ln), Some(rn)) => (ln, rn),
+    };
+    match (ln.color, rn.color) {
+        (Red, Red) => {
+            let mid = fuse(&ln.right, &rn.left);
+            match red(&mid) {
+                Some(m) => node(Red,
+                                node(Red, ln.left.clone(), ln.entry.clone(), m.left.clone()),
+                                m.entry.clone(),
+                                node(Red, m.right.clone(), rn.entry.clone(), rn.right.clone())),
+                None => node(Red, ln.left.clone(), ln.entry.clone(), node(Red, mid, rn.entry.clone(), rn.right.clone())),
+            }
+        }
+        (Black, Black) => {
+            let mid = fuse(&ln.right, &rn.left);
+            match red(&mid) {
+                Some(m) => node(Red,
+                                node(Black, ln.left.clone(), ln.entry.clone(), m.left.clone()),
+                                m.entry.clone(),
+                                node(Black, m.right.clone(), rn.entry.clone(), rn.right.clone())),
+                None => bal_left(ln.left.clone(), ln.entry.clone(), node(Black, mid, rn.entry.clone(), rn.right.clone())),
+            }
+        }
+        (_, Red) => node(Red, fuse(l, &rn.left), rn.entry.clone(), rn.right.clone()),
+        (Red, _) => node(Red, ln.left.clone(), ln.entry.clone(), fuse(&ln.right, r)),
+    }
+}
+
+impl<K, V> Clone for RedBlackMap<K, V> {
+    fn clone(&self) -> Self {
+        RedBlackMap { root: self.root.clone(), len: self.len }
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackMap<K, V> {
+    fn default() -> Self {
+        RedBlackMap::new()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for RedBlackMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for RedBlackMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for RedBlackMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for RedBlackMap<K, V> {
+    fn from_iter<I: IntoIterator<Item=(K, V)>>(iter: I) -> Self {
+        iter.into_iter().fold(RedBlackMap::new(), |acc, (k, v)| acc.insert(k, v))
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a RedBlackMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// [`Iterator`] of [`RedBlackMap`]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    stack: Vec<&'a Node<K, V>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut tree: &'a Tree<K, V>) {
+        while let Some(ref n) = *tree {
+            self.stack.push(n);
+            tree = &n.left;
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let n = self.stack.pop()?;
+        self.push_left(&n.right);
+        self.len -= 1;
+        Some((&n.entry.0, &n.entry.1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
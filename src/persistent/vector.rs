@@ -0,0 +1,284 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::iter::FromIterator;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T> {
+    Branch(Vec<Arc<Node<T>>>),
+    Leaf(Vec<Arc<T>>),
+}
+
+/// Persistent vector based on radix balanced trie with 32 branches.
+///
+/// [get](Vector::get), [set](Vector::set), [push_back](Vector::push_back) and [pop_back](Vector::pop_back)
+/// are O(log<sub>32</sub> n), only the path to the updated element is copied.
+pub struct Vector<T> {
+    root: Arc<Node<T>>,
+    shift: usize,
+    len: usize,
+}
+
+impl<T> Vector<T> {
+    /// Empty vector
+    pub fn new() -> Self {
+        Vector { root: Arc::new(Node::Leaf(Vec::new())), shift: 0, len: 0 }
+    }
+
+    /// Count of elements, O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there is no element
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Getting the element at index, `None` if out of bound
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [`usize`]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(&self.leaf(index)[index & MASK])
+    }
+
+    /// Getting the first element
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Getting the last element
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Replace the element at index, `None` if out of bound
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [`usize`]
+    /// * `x`: T
+    pub fn set(&self, index: usize, x: T) -> Option<Self> {
+        if index >= self.len {
+            return None;
+        }
+        Some(Vector { root: set(&self.root, self.shift, index, Arc::new(x)), shift: self.shift, len: self.len })
+    }
+
+    /// Append an element to the end
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn push_back(&self, x: T) -> Self {
+        let x = Arc::new(x);
+        if self.len == 1 << (self.shift + BITS) {
+            let root = Node::Branch(vec![self.root.clone(), new_path(self.shift, x)]);
+            return Vector { root: Arc::new(root), shift: self.shift + BITS, len: self.len + 1 };
+        }
+        Vector { root: push(&self.root, self.shift, self.len, x), shift: self.shift, len: self.len + 1 }
+    }
+
+    /// Remove the last element, `None` if empty
+    pub fn pop_back(&self) -> Option<Self> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut shift = self.shift;
+        let mut root = match pop(&self.root, shift, self.len - 1) {
+            Some(root) => root,
+            None => return Some(Vector::new()),
+        };
+        loop {
+            let only_child = match *root {
+                Node::Branch(ref children) if children.len() == 1 => children[0].clone(),
+                _ => break,
+            };
+            root = only_child;
+            shift -= BITS;
+        }
+        Some(Vector { root, shift, len: self.len - 1 })
+    }
+
+    /// [`Iterator`] over references of elements
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { vector: self, leaf: &[], front: 0, back: self.len }
+    }
+
+    fn leaf(&self, index: usize) -> &[Arc<T>] {
+        let mut node = &*self.root;
+        let mut shift = self.shift;
+        loop {
+            match *node {
+                Node::Branch(ref children) => {
+                    node = &children[(index >> shift) & MASK];
+                    shift -= BITS;
+                }
+                Node::Leaf(ref values) => return values,
+            }
+        }
+    }
+}
+
+fn new_path<T>(shift: usize, x: Arc<T>) -> Arc<Node<T>> {
+    if shift == 0 {
+        Arc::new(Node::Leaf(vec![x]))
+    } else {
+        Arc::new(Node::Branch(vec![new_path(shift - BITS, x)]))
+    }
+}
+
+fn set<T>(node: &Node<T>, shift: usize, index: usize, x: Arc<T>) -> Arc<Node<T>> {
+    match *node {
+        Node::Branch(ref children) => {
+            let mut children = children.clone();
+            let i = (index >> shift) & MASK;
+            children[i] = set(&children[i], shift - BITS, index, x);
+            Arc::new(Node::Branch(children))
+        }
+        Node::Leaf(ref values) => {
+            let mut values = values.clone();
+            values[index & MASK] = x;
+            Arc::new(Node::Leaf(values))
+        }
+    }
+}
+
+fn push<T>(node: &Node<T>, shift: usize, index: usize, x: Arc<T>) -> Arc<Node<T>> {
+    match *node {
+        Node::Branch(ref children) => {
+            let mut children = children.clone();
+            let i = (index >> shift) & MASK;
+            if i < children.len() {
+                children[i] = push(&children[i], shift - BITS, index, x);
+            } else {
+                children.push(new_path(shift - BITS, x));
+            }
+            Arc::new(Node::Branch(children))
+        }
+        Node::Leaf(ref values) => {
+            let mut values = values.clone();
+            values.push(x);
+            Arc::new(Node::Leaf(values))
+        }
+    }
+}
+
+/// Remove the element at index, which must be the last one, `None` if the node becomes empty
+fn pop<T>(node: &Node<T>, shift: usize, index: usize) -> Option<Arc<Node<T>>> {
+    match *node {
+        Node::Branch(ref children) => {
+            let mut children = children.clone();
+            let i = (index >> shift) & MASK;
+            match pop(&children[i], shift - BITS, index) {
+                Some(child) => children[i] = child,
+                None => {
+                    children.pop();
+                }
+            }
+            if children.is_empty() { None } else { Some(Arc::new(Node::Branch(children))) }
+        }
+        Node::Leaf(ref values) => {
+            let mut values = values.clone();
+            values.pop();
+            if values.is_empty() { None } else { Some(Arc::new(Node::Leaf(values))) }
+        }
+    }
+}
+
+impl<T> Clone for Vector<T> {
+    fn clone(&self) -> Self {
+        Vector { root: self.root.clone(), shift: self.shift, len: self.len }
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Vector::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Vector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Vector<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+        iter.into_iter().fold(Vector::new(), |acc, x| acc.push_back(x))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// [`Iterator`] of [`Vector`]
+pub struct Iter<'a, T: 'a> {
+    vector: &'a Vector<T>,
+    leaf: &'a [Arc<T>],
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front >= self.back {
+            return None;
+        }
+        if self.front & MASK == 0 || self.leaf.is_empty() {
+            self.leaf = self.vector.leaf(self.front);
+        }
+        let ret = &*self.leaf[self.front & MASK];
+        self.front += 1;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.vector.get(self.back)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
@@ -0,0 +1,30 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module transducer provide composable transformations which are independent of source and target,
+//! as Clojure transducers.
+//!
+//! A transducer turns a [`Reducer`] into another one, so a pipeline is just function composition
+//! and works with `compose!` (the first one sees the input first) and `pipe!` (the last one sees the input first).
+//! The whole pipeline runs in one pass and stops pulling the source once a step finishes early, e.g. [take].
+//!
+//! ```
+//! # #[macro_use] extern crate pa;
+//! # use pa::transducer::*;
+//! # fn main() {
+//! let xf = compose!(map(|x: i32| x * 2), filter(|x: &i32| x % 3 == 0), take(2));
+//! assert_eq!(into(xf, Vec::new(), 1..), [6, 12]);
+//! # }
+//! ```
+
+pub mod reducer;
+pub mod xform;
+pub mod run;
+
+pub use self::reducer::{Reducer, Step, FnReducer, ForEach, reducer, Conj};
+pub use self::xform::{Map, Filter, FilterNot, Take, Skip, map, filter, filter_not, take, skip};
+pub use self::run::{Eduction, Sink, transduce, into, eduction, sink};
@@ -0,0 +1,160 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use alloc::collections::{VecDeque, BTreeMap, BTreeSet};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use alloc::vec::Vec;
+use alloc::string::String;
+
+use crate::persistent;
+
+/// Result of a reducing step, `Break` stops the reduction early
+pub type Step<A> = ControlFlow<A, A>;
+
+/// Reducer, the step function `(A, T) -> A` of a reduction, which can finish early.
+pub trait Reducer<T> {
+    /// Type of the accumulation
+    type Acc;
+
+    /// Feed one element
+    ///
+    /// **Signature**: step :: Reducer T A -> A -> T -> Step A
+    fn step(&mut self, acc: Self::Acc, x: T) -> Step<Self::Acc>;
+}
+
+/// [`Reducer`] built from a fold function, see [reducer]
+pub struct FnReducer<F, A> {
+    f: F,
+    marker: PhantomData<fn(A) -> A>,
+}
+
+impl<T, A, F: FnMut(A, T) -> A> Reducer<T> for FnReducer<F, A> {
+    type Acc = A;
+
+    fn step(&mut self, acc: A, x: T) -> Step<A> {
+        Step::Continue((self.f)(acc, x))
+    }
+}
+
+/// Build a [`Reducer`] from a fold function, which never finishes early
+///
+/// # Arguments
+///
+/// * `f`: f :: (A, T) -> A
+pub fn reducer<T, A, F: FnMut(A, T) -> A>(f: F) -> FnReducer<F, A> {
+    FnReducer { f, marker: PhantomData }
+}
+
+/// [`Reducer`] calling a function for every element, used by [sink](transducer::run::sink)
+pub struct ForEach<F, T> {
+    f: F,
+    marker: PhantomData<fn(T)>,
+}
+
+impl<F: FnMut(T), T> ForEach<F, T> {
+    pub(crate) fn new(f: F) -> Self {
+        ForEach { f, marker: PhantomData }
+    }
+}
+
+impl<T, F: FnMut(T)> Reducer<T> for ForEach<F, T> {
+    type Acc = ();
+
+    fn step(&mut self, _: (), x: T) -> Step<()> {
+        (self.f)(x);
+        Step::Continue(())
+    }
+}
+
+/// Collection which can be built by adding elements one by one, used by [into](transducer::run::into).
+pub trait Conj<T> {
+    /// Add an element, [`persistent::List`] adds to the front
+    ///
+    /// **Signature**: conj :: C T -> T -> C T
+    fn conj(self, x: T) -> Self;
+}
+
+impl<T> Conj<T> for Vec<T> {
+    fn conj(mut self, x: T) -> Self {
+        self.push(x);
+        self
+    }
+}
+
+impl<T> Conj<T> for VecDeque<T> {
+    fn conj(mut self, x: T) -> Self {
+        self.push_back(x);
+        self
+    }
+}
+
+impl Conj<char> for String {
+    fn conj(mut self, x: char) -> Self {
+        self.push(x);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq> Conj<T> for HashSet<T> {
+    fn conj(mut self, x: T) -> Self {
+        self.insert(x);
+        self
+    }
+}
+
+impl<T: Ord> Conj<T> for BTreeSet<T> {
+    fn conj(mut self, x: T) -> Self {
+        self.insert(x);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V> Conj<(K, V)> for HashMap<K, V> {
+    fn conj(mut self, (k, v): (K, V)) -> Self {
+        self.insert(k, v);
+        self
+    }
+}
+
+impl<K: Ord, V> Conj<(K, V)> for BTreeMap<K, V> {
+    fn conj(mut self, (k, v): (K, V)) -> Self {
+        self.insert(k, v);
+        self
+    }
+}
+
+impl<T> Conj<T> for persistent::List<T> {
+    fn conj(self, x: T) -> Self {
+        self.cons(x)
+    }
+}
+
+impl<T> Conj<T> for persistent::Vector<T> {
+    fn conj(self, x: T) -> Self {
+        self.push_back(x)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V> Conj<(K, V)> for persistent::HashMap<K, V> {
+    fn conj(self, (k, v): (K, V)) -> Self {
+        self.insert(k, v)
+    }
+}
+
+impl<K: Ord, V> Conj<(K, V)> for persistent::RedBlackMap<K, V> {
+    fn conj(self, (k, v): (K, V)) -> Self {
+        self.insert(k, v)
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::collections::VecDeque;
+use core::marker::PhantomData;
+
+use super::reducer::{Reducer, Step, FnReducer, ForEach, reducer, Conj};
+
+/// Run a transducer over an [`Iterator`] with a fold function, stops pulling once finished
+///
+/// # Arguments
+///
+/// * `xf`: transducer
+/// * `f`: f :: (A, U) -> A, fold function
+/// * `init`: initial point of folding
+/// * `it`: [`Iterator`] T
+pub fn transduce<T, U, A, F, X, R>(xf: X, f: F, init: A, it: impl IntoIterator<Item=T>) -> A
+    where F: FnMut(A, U) -> A, X: FnOnce(FnReducer<F, A>) -> R, R: Reducer<T, Acc=A>
+{
+    reduce(&mut xf(reducer(f)), init, it)
+}
+
+/// Run a transducer over an [`Iterator`] and add results into a collection, e.g. [`Vec`] or [`persistent::Vector`](persistent::Vector)
+///
+/// # Arguments
+///
+/// * `xf`: transducer
+/// * `init`: [`Conj`] C => C U, collection to add into
+/// * `it`: [`Iterator`] T
+pub fn into<T, U, C, X, R>(xf: X, init: C, it: impl IntoIterator<Item=T>) -> C
+    where C: Conj<U>, X: FnOnce(FnReducer<fn(C, U) -> C, C>) -> R, R: Reducer<T, Acc=C>
+{
+    transduce(xf, <C as Conj<U>>::conj as fn(C, U) -> C, init, it)
+}
+
+fn reduce<T, R: Reducer<T>>(r: &mut R, init: R::Acc, it: impl IntoIterator<Item=T>) -> R::Acc {
+    let mut acc = init;
+    for x in it {
+        match r.step(acc, x) {
+            Step::Continue(next) => acc = next,
+            Step::Break(done) => return done,
+        }
+    }
+    acc
+}
+
+type Buffer<U> = FnReducer<fn(VecDeque<U>, U) -> VecDeque<U>, VecDeque<U>>;
+
+/// Lazy [`Iterator`] applying a transducer, see [eduction]
+pub struct Eduction<I, R, U> {
+    it: I,
+    r: R,
+    buf: VecDeque<U>,
+    done: bool,
+}
+
+impl<I: Iterator, R: Reducer<I::Item, Acc=VecDeque<U>>, U> Iterator for Eduction<I, R, U> {
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        while self.buf.is_empty() && !self.done {
+            let x = match self.it.next() {
+                Some(x) => x,
+                None => break,
+            };
+            let buf = ::core::mem::take(&mut self.buf);
+            self.buf = match self.r.step(buf, x) {
+                Step::Continue(buf) => buf,
+                Step::Break(buf) => {
+                    self.done = true;
+                    buf
+                }
+            };
+        }
+        self.buf.pop_front()
+    }
+}
+
+/// Apply a transducer to an [`Iterator`] lazily, source is pulled only when needed
+///
+/// # Arguments
+///
+/// * `xf`: transducer
+/// * `it`: [`Iterator`] T
+pub fn eduction<I, U, X, R>(xf: X, it: I) -> Eduction<I::IntoIter, R, U>
+    where I: IntoIterator, X: FnOnce(Buffer<U>) -> R, R: Reducer<I::Item, Acc=VecDeque<U>>
+{
+    Eduction { it: it.into_iter(), r: xf(reducer(<VecDeque<U> as Conj<U>>::conj as fn(VecDeque<U>, U) -> VecDeque<U>)), buf: VecDeque::new(), done: false }
+}
+
+/// Push-based consumer of a transducer, see [sink]
+pub struct Sink<R, T> {
+    r: R,
+    done: bool,
+    marker: PhantomData<fn(T)>,
+}
+
+impl<T, R: Reducer<T, Acc=()>> Sink<R, T> {
+    /// Push an element, return `false` if the pipeline has finished and the element is ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: T
+    pub fn push(&mut self, x: T) -> bool {
+        if self.done {
+            return false;
+        }
+        if let Step::Break(()) = self.r.step((), x) {
+            self.done = true;
+        }
+        true
+    }
+
+    /// Check if the pipeline has finished
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+/// Build a push-based consumer which calls `f` for every result of the transducer
+///
+/// # Arguments
+///
+/// * `xf`: transducer
+/// * `f`: f :: U -> ()
+pub fn sink<T, U, F, X, R>(xf: X, f: F) -> Sink<R, T>
+    where F: FnMut(U), X: FnOnce(ForEach<F, U>) -> R, R: Reducer<T, Acc=()>
+{
+    Sink { r: xf(ForEach::new(f)), done: false, marker: PhantomData }
+}
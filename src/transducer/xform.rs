@@ -0,0 +1,138 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::marker::PhantomData;
+
+use super::reducer::{Reducer, Step};
+
+/// [`Reducer`] mapping every element before the next one, see [map]
+pub struct Map<F, R, U> {
+    f: F,
+    next: R,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl<T, U, F: FnMut(T) -> U, R: Reducer<U>> Reducer<T> for Map<F, R, U> {
+    type Acc = R::Acc;
+
+    fn step(&mut self, acc: R::Acc, x: T) -> Step<R::Acc> {
+        self.next.step(acc, (self.f)(x))
+    }
+}
+
+/// Transducer counterpart of [map](basic_fn::fun::map)
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> U
+pub fn map<F, R, U>(f: F) -> impl FnOnce(R) -> Map<F, R, U> {
+    move |next| Map { f, next, marker: PhantomData }
+}
+
+/// [`Reducer`] passing elements matching a predicate only, see [filter]
+pub struct Filter<P, R> {
+    p: P,
+    next: R,
+}
+
+impl<T, P: FnMut(&T) -> bool, R: Reducer<T>> Reducer<T> for Filter<P, R> {
+    type Acc = R::Acc;
+
+    fn step(&mut self, acc: R::Acc, x: T) -> Step<R::Acc> {
+        if (self.p)(&x) { self.next.step(acc, x) } else { Step::Continue(acc) }
+    }
+}
+
+/// Transducer counterpart of [filter](basic_fn::fun::filter)
+///
+/// # Arguments
+///
+/// * `p`: p :: T -> [`bool`], function to filter item
+pub fn filter<P, R>(p: P) -> impl FnOnce(R) -> Filter<P, R> {
+    move |next| Filter { p, next }
+}
+
+/// [`Reducer`] dropping elements matching a predicate, see [filter_not]
+pub struct FilterNot<P, R> {
+    p: P,
+    next: R,
+}
+
+impl<T, P: FnMut(&T) -> bool, R: Reducer<T>> Reducer<T> for FilterNot<P, R> {
+    type Acc = R::Acc;
+
+    fn step(&mut self, acc: R::Acc, x: T) -> Step<R::Acc> {
+        if (self.p)(&x) { Step::Continue(acc) } else { self.next.step(acc, x) }
+    }
+}
+
+/// Transducer counterpart of [filter_not](basic_fn::fun::filter_not)
+///
+/// # Arguments
+///
+/// * `p`: p :: T -> [`bool`], function to reverse filter item
+pub fn filter_not<P, R>(p: P) -> impl FnOnce(R) -> FilterNot<P, R> {
+    move |next| FilterNot { p, next }
+}
+
+/// [`Reducer`] passing first n elements then finishing, see [take]
+pub struct Take<R> {
+    n: usize,
+    next: R,
+}
+
+impl<T, R: Reducer<T>> Reducer<T> for Take<R> {
+    type Acc = R::Acc;
+
+    fn step(&mut self, acc: R::Acc, x: T) -> Step<R::Acc> {
+        if self.n == 0 {
+            return Step::Break(acc);
+        }
+        self.n -= 1;
+        match self.next.step(acc, x) {
+            Step::Continue(acc) if self.n == 0 => Step::Break(acc),
+            step => step,
+        }
+    }
+}
+
+/// Transducer counterpart of [take](basic_fn::fun::take), the reduction finishes after n elements
+///
+/// # Arguments
+///
+/// * `n`: elements count to take
+pub fn take<R>(n: usize) -> impl FnOnce(R) -> Take<R> {
+    move |next| Take { n, next }
+}
+
+/// [`Reducer`] dropping first n elements, see [skip]
+pub struct Skip<R> {
+    n: usize,
+    next: R,
+}
+
+impl<T, R: Reducer<T>> Reducer<T> for Skip<R> {
+    type Acc = R::Acc;
+
+    fn step(&mut self, acc: R::Acc, x: T) -> Step<R::Acc> {
+        if self.n > 0 {
+            self.n -= 1;
+            Step::Continue(acc)
+        } else {
+            self.next.step(acc, x)
+        }
+    }
+}
+
+/// Transducer counterpart of [skip](basic_fn::fun::skip)
+///
+/// # Arguments
+///
+/// * `n`: elements count to skip
+pub fn skip<R>(n: usize) -> impl FnOnce(R) -> Skip<R> {
+    move |next| Skip { n, next }
+}
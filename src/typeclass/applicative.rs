@@ -0,0 +1,55 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::hkt::{Kind, Applied};
+use super::functor::Functor;
+
+/// Lift a value into minimal context, i.e. `pure`/`return` in Haskell
+pub trait Pure: Kind {
+    /// **Signature**: pure :: T -> F T
+    fn pure(x: Self::Item) -> Self;
+}
+
+/// Applicative typeclass, a [`Functor`] which can apply wrapped functions.
+///
+/// Laws:
+/// 1. identity: `x.ap(pure(id)) == x`
+/// 2. homomorphism: `pure(x).ap(pure(f)) == pure(f(x))`
+/// 3. interchange: `pure(x).ap(fs) == fs.ap(pure(|f| f(x)))`
+pub trait Applicative: Functor + Pure {
+    /// Lift a value into same type constructor with another inner type
+    ///
+    /// **Signature**: pure_of :: U -> F U
+    fn pure_of<U>(x: U) -> Applied<Self, U>;
+
+    /// **Signature**: ap :: F T -> F (T -> U) -> F U
+    fn ap<U, F: FnMut(Self::Item) -> U>(self, fs: Applied<Self, F>) -> Applied<Self, U>;
+
+    /// **Signature**: lift_a2 :: F T -> F B -> (T -> B -> U) -> F U
+    ///
+    /// `B` must be [`Clone`] as some instance, e.g. [`Vec`], combines every pair of elements.
+    fn lift_a2<B: Clone, U>(self, fb: Applied<Self, B>, f: impl FnMut(Self::Item, B) -> U) -> Applied<Self, U>;
+}
+
+/// Lift a value into an [`Applicative`] by its type
+///
+/// # Arguments
+///
+/// * `x`: T
+pub fn pure<F: Pure>(x: F::Item) -> F {
+    F::pure(x)
+}
+
+/// Apply wrapped functions to wrapped values
+///
+/// # Arguments
+///
+/// * `fs`: F (T -> U)
+/// * `fa`: [`Applicative`] F => F T
+pub fn ap<A: Applicative, U, F: FnMut(A::Item) -> U>(fs: Applied<A, F>, fa: A) -> Applied<A, U> {
+    fa.ap(fs)
+}
@@ -0,0 +1,46 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::hkt::Kind;
+use super::monoid::Monoid;
+
+/// Foldable typeclass, a container which can be reduced to a summary value.
+pub trait Foldable: Kind + Sized {
+    /// Fold from the beginning, same as [foldl](basic_fn::fun::foldl)
+    ///
+    /// **Signature**: foldl :: F T -> R -> (R -> T -> R) -> R
+    fn foldl<R>(self, init: R, f: impl FnMut(R, Self::Item) -> R) -> R;
+
+    /// Fold from the end, same as [foldr](basic_fn::fun::foldr)
+    ///
+    /// **Signature**: foldr :: F T -> R -> (R -> T -> R) -> R
+    fn foldr<R>(self, init: R, f: impl FnMut(R, Self::Item) -> R) -> R;
+
+    /// Map every element into a [`Monoid`] and combine them from the beginning
+    ///
+    /// **Signature**: fold_map :: F T -> (T -> M) -> M
+    fn fold_map<M: Monoid>(self, mut f: impl FnMut(Self::Item) -> M) -> M {
+        self.foldl(M::mempty(), |acc, x| acc.mappend(f(x)))
+    }
+
+    /// Count of elements
+    ///
+    /// **Signature**: length :: F T -> [`usize`]
+    fn length(self) -> usize {
+        self.foldl(0, |acc, _| acc + 1)
+    }
+}
+
+/// Map every element of a [`Foldable`] into a [`Monoid`] and combine them
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> M
+/// * `fa`: [`Foldable`] F => F T
+pub fn fold_map<F: Foldable, M: Monoid>(f: impl FnMut(F::Item) -> M, fa: F) -> M {
+    fa.fold_map(f)
+}
@@ -0,0 +1,28 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::hkt::{Kind, Applied};
+
+/// Functor typeclass, a container which can be mapped over.
+///
+/// Laws:
+/// 1. identity: `x.fmap(id) == x`
+/// 2. composition: `x.fmap(compose!(f, g)) == x.fmap(g).fmap(f)`
+pub trait Functor: Kind + Sized {
+    /// **Signature**: fmap :: F T -> (T -> U) -> F U
+    fn fmap<U>(self, f: impl FnMut(Self::Item) -> U) -> Applied<Self, U>;
+}
+
+/// Generalised [map](basic_fn::fun::map) over any [`Functor`]
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> U
+/// * `fa`: [`Functor`] F => F T
+pub fn fmap<F: Functor, U>(f: impl FnMut(F::Item) -> U, fa: F) -> Applied<F, U> {
+    fa.fmap(f)
+}
@@ -0,0 +1,32 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Emulation of higher-kinded types.
+//!
+//! Rust cannot abstract over a type constructor like `Option` directly.
+//! Instead a concrete type `F<T>` tells which `T` it holds and how to become `F<U>` via [`Kind`].
+//!
+//! ```
+//! use pa::typeclass::Kind;
+//!
+//! struct Id<T>(T);
+//! impl<T> Kind for Id<T> {
+//!     type Item = T;
+//!     type Of<U> = Id<U>;
+//! }
+//! ```
+
+/// Type constructor applied to a type, i.e. `F<T>`
+pub trait Kind {
+    /// The `T` of `F<T>`
+    type Item;
+    /// Same type constructor applied to `U`, i.e. `F<U>`
+    type Of<U>: Kind<Item=U>;
+}
+
+/// Shorthand of `F<U>` for a `F<T>`
+pub type Applied<F, U> = <F as Kind>::Of<U>;
@@ -0,0 +1,71 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::boxed::Box;
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+
+impl<T> Kind for Box<T> {
+    type Item = T;
+    type Of<U> = Box<U>;
+}
+
+impl<T> Functor for Box<T> {
+    fn fmap<U>(self, mut f: impl FnMut(T) -> U) -> Box<U> {
+        Box::new(f(*self))
+    }
+}
+
+impl<T> Pure for Box<T> {
+    fn pure(x: T) -> Self {
+        Box::new(x)
+    }
+}
+
+impl<T> Applicative for Box<T> {
+    fn pure_of<U>(x: U) -> Box<U> {
+        Box::new(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Box<F>) -> Box<U> {
+        let mut f = *fs;
+        Box::new(f(*self))
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Box<B>, mut f: impl FnMut(T, B) -> U) -> Box<U> {
+        Box::new(f(*self, *fb))
+    }
+}
+
+impl<T> Monad for Box<T> {
+    fn bind<U>(self, mut f: impl FnMut(T) -> Box<U>) -> Box<U> {
+        f(*self)
+    }
+}
+
+impl<T> Foldable for Box<T> {
+    fn foldl<R>(self, init: R, mut f: impl FnMut(R, T) -> R) -> R {
+        f(init, *self)
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.foldl(init, f)
+    }
+}
+
+impl<T> Traversable for Box<T> {
+    fn traverse<G, U>(self, mut f: impl FnMut(T) -> G) -> Applied<G, Box<U>>
+        where G: Applicative<Item=U>, U: Clone, Box<U>: Clone
+    {
+        f(*self).lift_a2(G::pure_of(()), |u, _| Box::new(u))
+    }
+}
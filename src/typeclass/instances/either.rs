@@ -0,0 +1,76 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::adt::Either::{self, Left, Right};
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+
+impl<L, R> Kind for Either<L, R> {
+    type Item = R;
+    type Of<U> = Either<L, U>;
+}
+
+impl<L, R> Functor for Either<L, R> {
+    fn fmap<U>(self, f: impl FnMut(R) -> U) -> Either<L, U> {
+        self.map_right(f)
+    }
+}
+
+impl<L, R> Pure for Either<L, R> {
+    fn pure(x: R) -> Self {
+        Right(x)
+    }
+}
+
+/// The first `Left` is kept
+impl<L, R> Applicative for Either<L, R> {
+    fn pure_of<U>(x: U) -> Either<L, U> {
+        Right(x)
+    }
+
+    fn ap<U, F: FnMut(R) -> U>(self, fs: Either<L, F>) -> Either<L, U> {
+        fs.and_then(|f| self.map_right(f))
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Either<L, B>, mut f: impl FnMut(R, B) -> U) -> Either<L, U> {
+        self.and_then(|x| fb.map_right(|y| f(x, y)))
+    }
+}
+
+impl<L, R> Monad for Either<L, R> {
+    fn bind<U>(self, f: impl FnMut(R) -> Either<L, U>) -> Either<L, U> {
+        self.and_then(f)
+    }
+}
+
+impl<L, R> Foldable for Either<L, R> {
+    fn foldl<A>(self, init: A, mut f: impl FnMut(A, R) -> A) -> A {
+        match self {
+            Left(_) => init,
+            Right(x) => f(init, x),
+        }
+    }
+
+    fn foldr<A>(self, init: A, f: impl FnMut(A, R) -> A) -> A {
+        self.foldl(init, f)
+    }
+}
+
+impl<L, R> Traversable for Either<L, R> {
+    fn traverse<G, U>(self, mut f: impl FnMut(R) -> G) -> Applied<G, Either<L, U>>
+        where G: Applicative<Item=U>, U: Clone, Either<L, U>: Clone
+    {
+        match self {
+            Left(l) => G::pure_of(Left(l)),
+            Right(x) => f(x).lift_a2(G::pure_of(()), |u, _| Right(u)),
+        }
+    }
+}
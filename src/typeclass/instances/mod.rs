@@ -0,0 +1,22 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Typeclass instances of std struct and enum, and ADTs of [adt](adt).
+
+mod option;
+mod result;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+mod vec_deque;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod tuple;
+mod either;
+mod validated;
+#[cfg(feature = "alloc")]
+mod non_empty;
@@ -0,0 +1,74 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::adt::{NonEmptyVec, NonEmptyList};
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monoid::Semigroup;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::{Traversable, traverse_seq};
+
+impl<T> Semigroup for NonEmptyVec<T> {
+    fn mappend(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T: Clone> Semigroup for NonEmptyList<T> {
+    fn mappend(self, other: Self) -> Self {
+        let front: Vec<T> = self.iter().cloned().collect();
+        front.into_iter().rev().fold(other, |acc, x| acc.cons(x))
+    }
+}
+
+impl<T> Kind for NonEmptyVec<T> {
+    type Item = T;
+    type Of<U> = NonEmptyVec<U>;
+}
+
+impl<T> Functor for NonEmptyVec<T> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> NonEmptyVec<U> {
+        self.map(f)
+    }
+}
+
+impl<T> Pure for NonEmptyVec<T> {
+    fn pure(x: T) -> Self {
+        NonEmptyVec::new(x)
+    }
+}
+
+impl<T> Foldable for NonEmptyVec<T> {
+    fn foldl<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().fold(init, f)
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().rev().fold(init, f)
+    }
+}
+
+impl<T> Traversable for NonEmptyVec<T> {
+    fn traverse<G, U>(self, f: impl FnMut(T) -> G) -> Applied<G, NonEmptyVec<U>>
+        where G: Applicative<Item=U>, U: Clone, NonEmptyVec<U>: Clone
+    {
+        let mut gs = self.into_iter().map(f);
+        let head = gs.next().expect("NonEmptyVec is never empty");
+        let tail = traverse_seq(gs.collect(), Vec::new(), |mut rev, u| {
+            rev.push(u);
+            rev
+        }, |mut rev| {
+            rev.reverse();
+            rev
+        });
+        head.lift_a2(tail, NonEmptyVec::from_parts)
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+
+impl<T> Kind for Option<T> {
+    type Item = T;
+    type Of<U> = Option<U>;
+}
+
+impl<T> Functor for Option<T> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> Option<U> {
+        self.map(f)
+    }
+}
+
+impl<T> Pure for Option<T> {
+    fn pure(x: T) -> Self {
+        Some(x)
+    }
+}
+
+impl<T> Applicative for Option<T> {
+    fn pure_of<U>(x: U) -> Option<U> {
+        Some(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Option<F>) -> Option<U> {
+        match (self, fs) {
+            (Some(x), Some(mut f)) => Some(f(x)),
+            _ => None,
+        }
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Option<B>, mut f: impl FnMut(T, B) -> U) -> Option<U> {
+        match (self, fb) {
+            (Some(x), Some(y)) => Some(f(x, y)),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Monad for Option<T> {
+    fn bind<U>(self, f: impl FnMut(T) -> Option<U>) -> Option<U> {
+        self.and_then(f)
+    }
+}
+
+impl<T> Foldable for Option<T> {
+    fn foldl<R>(self, init: R, mut f: impl FnMut(R, T) -> R) -> R {
+        match self {
+            Some(x) => f(init, x),
+            None => init,
+        }
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.foldl(init, f)
+    }
+}
+
+impl<T> Traversable for Option<T> {
+    fn traverse<G, U>(self, mut f: impl FnMut(T) -> G) -> Applied<G, Option<U>>
+        where G: Applicative<Item=U>, U: Clone, Option<U>: Clone
+    {
+        match self {
+            Some(x) => f(x).lift_a2(G::pure_of(()), |u, _| Some(u)),
+            None => G::pure_of(None),
+        }
+    }
+}
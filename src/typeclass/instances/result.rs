@@ -0,0 +1,77 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+
+impl<T, E> Kind for Result<T, E> {
+    type Item = T;
+    type Of<U> = Result<U, E>;
+}
+
+impl<T, E> Functor for Result<T, E> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> Result<U, E> {
+        self.map(f)
+    }
+}
+
+impl<T, E> Pure for Result<T, E> {
+    fn pure(x: T) -> Self {
+        Ok(x)
+    }
+}
+
+/// The first `Err` is kept, use [`Validated`](adt::Validated) to accumulate all errors
+impl<T, E> Applicative for Result<T, E> {
+    fn pure_of<U>(x: U) -> Result<U, E> {
+        Ok(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Result<F, E>) -> Result<U, E> {
+        let f = fs?;
+        self.map(f)
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Result<B, E>, mut f: impl FnMut(T, B) -> U) -> Result<U, E> {
+        let x = self?;
+        fb.map(|y| f(x, y))
+    }
+}
+
+impl<T, E> Monad for Result<T, E> {
+    fn bind<U>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<U, E> {
+        self.and_then(f)
+    }
+}
+
+impl<T, E> Foldable for Result<T, E> {
+    fn foldl<R>(self, init: R, mut f: impl FnMut(R, T) -> R) -> R {
+        match self {
+            Ok(x) => f(init, x),
+            Err(_) => init,
+        }
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.foldl(init, f)
+    }
+}
+
+impl<T, E> Traversable for Result<T, E> {
+    fn traverse<G, U>(self, mut f: impl FnMut(T) -> G) -> Applied<G, Result<U, E>>
+        where G: Applicative<Item=U>, U: Clone, Result<U, E>: Clone
+    {
+        match self {
+            Ok(x) => f(x).lift_a2(G::pure_of(()), |u, _| Ok(u)),
+            Err(e) => G::pure_of(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+use crate::typeclass::monoid::Monoid;
+
+/// The first element is the context, the second one is the value, i.e. Writer in Haskell
+impl<A, T> Kind for (A, T) {
+    type Item = T;
+    type Of<U> = (A, U);
+}
+
+impl<A, T> Functor for (A, T) {
+    fn fmap<U>(self, mut f: impl FnMut(T) -> U) -> (A, U) {
+        (self.0, f(self.1))
+    }
+}
+
+impl<A: Monoid, T> Pure for (A, T) {
+    fn pure(x: T) -> Self {
+        (A::mempty(), x)
+    }
+}
+
+/// Contexts are combined by [`Monoid`]
+impl<A: Monoid, T> Applicative for (A, T) {
+    fn pure_of<U>(x: U) -> (A, U) {
+        (A::mempty(), x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: (A, F)) -> (A, U) {
+        let (a, mut f) = fs;
+        (a.mappend(self.0), f(self.1))
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: (A, B), mut f: impl FnMut(T, B) -> U) -> (A, U) {
+        (self.0.mappend(fb.0), f(self.1, fb.1))
+    }
+}
+
+impl<A: Monoid, T> Monad for (A, T) {
+    fn bind<U>(self, mut f: impl FnMut(T) -> (A, U)) -> (A, U) {
+        let (a, u) = f(self.1);
+        (self.0.mappend(a), u)
+    }
+}
+
+impl<A, T> Foldable for (A, T) {
+    fn foldl<R>(self, init: R, mut f: impl FnMut(R, T) -> R) -> R {
+        f(init, self.1)
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.foldl(init, f)
+    }
+}
+
+impl<A: Clone, T> Traversable for (A, T) {
+    fn traverse<G, U>(self, mut f: impl FnMut(T) -> G) -> Applied<G, (A, U)>
+        where G: Applicative<Item=U>, U: Clone, (A, U): Clone
+    {
+        f(self.1).lift_a2(G::pure_of(self.0), |u, a| (a, u))
+    }
+}
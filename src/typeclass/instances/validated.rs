@@ -0,0 +1,78 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::adt::Validated::{self, Valid, Invalid};
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monoid::Semigroup;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::Traversable;
+
+impl<E, T> Kind for Validated<E, T> {
+    type Item = T;
+    type Of<U> = Validated<E, U>;
+}
+
+impl<E, T> Functor for Validated<E, T> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> Validated<E, U> {
+        self.map(f)
+    }
+}
+
+impl<E, T> Pure for Validated<E, T> {
+    fn pure(x: T) -> Self {
+        Valid(x)
+    }
+}
+
+/// Errors of both sides are combined, the left one first
+impl<E: Semigroup, T> Applicative for Validated<E, T> {
+    fn pure_of<U>(x: U) -> Validated<E, U> {
+        Valid(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Validated<E, F>) -> Validated<E, U> {
+        match (fs, self) {
+            (Valid(mut f), Valid(x)) => Valid(f(x)),
+            (Invalid(e1), Invalid(e2)) => Invalid(e1.mappend(e2)),
+            (Invalid(e), _) | (_, Invalid(e)) => Invalid(e),
+        }
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Validated<E, B>, mut f: impl FnMut(T, B) -> U) -> Validated<E, U> {
+        match (self, fb) {
+            (Valid(x), Valid(y)) => Valid(f(x, y)),
+            (Invalid(e1), Invalid(e2)) => Invalid(e1.mappend(e2)),
+            (Invalid(e), _) | (_, Invalid(e)) => Invalid(e),
+        }
+    }
+}
+
+impl<E, T> Foldable for Validated<E, T> {
+    fn foldl<R>(self, init: R, mut f: impl FnMut(R, T) -> R) -> R {
+        match self {
+            Valid(x) => f(init, x),
+            Invalid(_) => init,
+        }
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.foldl(init, f)
+    }
+}
+
+impl<E, T> Traversable for Validated<E, T> {
+    fn traverse<G, U>(self, mut f: impl FnMut(T) -> G) -> Applied<G, Validated<E, U>>
+        where G: Applicative<Item=U>, U: Clone, Validated<E, U>: Clone
+    {
+        match self {
+            Valid(x) => f(x).lift_a2(G::pure_of(()), |u, _| Valid(u)),
+            Invalid(e) => G::pure_of(Invalid(e)),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::{Traversable, traverse_seq};
+
+impl<T> Kind for Vec<T> {
+    type Item = T;
+    type Of<U> = Vec<U>;
+}
+
+impl<T> Functor for Vec<T> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> Vec<U> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T> Pure for Vec<T> {
+    fn pure(x: T) -> Self {
+        vec![x]
+    }
+}
+
+/// Every function is applied to every element, i.e. cartesian product
+impl<T: Clone> Applicative for Vec<T> {
+    fn pure_of<U>(x: U) -> Vec<U> {
+        vec![x]
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Vec<F>) -> Vec<U> {
+        let mut ret = Vec::with_capacity(self.len() * fs.len());
+        for mut f in fs {
+            ret.extend(self.iter().cloned().map(&mut f));
+        }
+        ret
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Vec<B>, mut f: impl FnMut(T, B) -> U) -> Vec<U> {
+        let mut ret = Vec::with_capacity(self.len() * fb.len());
+        for x in self {
+            ret.extend(fb.iter().map(|y| f(x.clone(), y.clone())));
+        }
+        ret
+    }
+}
+
+impl<T: Clone> Monad for Vec<T> {
+    fn bind<U>(self, f: impl FnMut(T) -> Vec<U>) -> Vec<U> {
+        self.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<T> Foldable for Vec<T> {
+    fn foldl<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().fold(init, f)
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().rev().fold(init, f)
+    }
+
+    fn length(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Traversable for Vec<T> {
+    fn traverse<G, U>(self, f: impl FnMut(T) -> G) -> Applied<G, Vec<U>>
+        where G: Applicative<Item=U>, U: Clone, Vec<U>: Clone
+    {
+        let gs = self.into_iter().map(f).collect();
+        traverse_seq(gs, Vec::new(), |mut rev, u| {
+            rev.push(u);
+            rev
+        }, |mut rev| {
+            rev.reverse();
+            rev
+        })
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::collections::VecDeque;
+
+use crate::typeclass::hkt::{Kind, Applied};
+use crate::typeclass::functor::Functor;
+use crate::typeclass::applicative::{Pure, Applicative};
+use crate::typeclass::monad::Monad;
+use crate::typeclass::foldable::Foldable;
+use crate::typeclass::traversable::{Traversable, traverse_seq};
+
+impl<T> Kind for VecDeque<T> {
+    type Item = T;
+    type Of<U> = VecDeque<U>;
+}
+
+impl<T> Functor for VecDeque<T> {
+    fn fmap<U>(self, f: impl FnMut(T) -> U) -> VecDeque<U> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T> Pure for VecDeque<T> {
+    fn pure(x: T) -> Self {
+        let mut ret = VecDeque::with_capacity(1);
+        ret.push_back(x);
+        ret
+    }
+}
+
+/// Every function is applied to every element, i.e. cartesian product
+impl<T: Clone> Applicative for VecDeque<T> {
+    fn pure_of<U>(x: U) -> VecDeque<U> {
+        Pure::pure(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: VecDeque<F>) -> VecDeque<U> {
+        let mut ret = VecDeque::with_capacity(self.len() * fs.len());
+        for mut f in fs {
+            ret.extend(self.iter().cloned().map(&mut f));
+        }
+        ret
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: VecDeque<B>, mut f: impl FnMut(T, B) -> U) -> VecDeque<U> {
+        let mut ret = VecDeque::with_capacity(self.len() * fb.len());
+        for x in self {
+            ret.extend(fb.iter().map(|y| f(x.clone(), y.clone())));
+        }
+        ret
+    }
+}
+
+impl<T: Clone> Monad for VecDeque<T> {
+    fn bind<U>(self, f: impl FnMut(T) -> VecDeque<U>) -> VecDeque<U> {
+        self.into_iter().flat_map(f).collect()
+    }
+}
+
+impl<T> Foldable for VecDeque<T> {
+    fn foldl<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().fold(init, f)
+    }
+
+    fn foldr<R>(self, init: R, f: impl FnMut(R, T) -> R) -> R {
+        self.into_iter().rev().fold(init, f)
+    }
+
+    fn length(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Traversable for VecDeque<T> {
+    fn traverse<G, U>(self, f: impl FnMut(T) -> G) -> Applied<G, VecDeque<U>>
+        where G: Applicative<Item=U>, U: Clone, VecDeque<U>: Clone
+    {
+        let gs = self.into_iter().map(f).collect();
+        traverse_seq(gs, VecDeque::new(), |mut ret, u| {
+            ret.push_front(u);
+            ret
+        }, |ret| ret)
+    }
+}
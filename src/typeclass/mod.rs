@@ -0,0 +1,28 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Module typeclass provide Haskell-like typeclasses as traits.
+//! Higher-kinded types are emulated by [`Kind`], please refer [hkt](typeclass::hkt) for details.
+
+pub mod hkt;
+pub mod functor;
+pub mod applicative;
+pub mod monad;
+pub mod monoid;
+pub mod foldable;
+pub mod traversable;
+mod instances;
+
+pub use self::hkt::{Kind, Applied};
+pub use self::functor::{Functor, fmap};
+pub use self::applicative::{Pure, Applicative, pure, ap};
+pub use self::monad::{Monad, bind};
+pub use self::monoid::{Semigroup, Monoid, mappend, mempty, mconcat};
+pub use self::foldable::{Foldable, fold_map};
+pub use self::traversable::{Traversable, traverse, sequence};
+#[cfg(feature = "alloc")]
+pub use self::traversable::{traverse_iter, sequence_iter};
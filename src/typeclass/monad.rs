@@ -0,0 +1,30 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::hkt::Applied;
+use super::applicative::Applicative;
+
+/// Monad typeclass, an [`Applicative`] which can sequence dependent computations.
+///
+/// Laws:
+/// 1. left identity: `pure(x).bind(f) == f(x)`
+/// 2. right identity: `m.bind(pure) == m`
+/// 3. associativity: `m.bind(f).bind(g) == m.bind(|x| f(x).bind(g))`
+pub trait Monad: Applicative {
+    /// **Signature**: bind :: M T -> (T -> M U) -> M U
+    fn bind<U>(self, f: impl FnMut(Self::Item) -> Applied<Self, U>) -> Applied<Self, U>;
+}
+
+/// Sequence a [`Monad`] by a function which returns next [`Monad`], i.e. `>>=` in Haskell
+///
+/// # Arguments
+///
+/// * `f`: f :: T -> M U
+/// * `m`: [`Monad`] M => M T
+pub fn bind<M: Monad, U>(f: impl FnMut(M::Item) -> Applied<M, U>, m: M) -> Applied<M, U> {
+    m.bind(f)
+}
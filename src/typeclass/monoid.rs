@@ -0,0 +1,151 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Semigroup typeclass, a type with an associative binary operation.
+///
+/// Law:
+/// associativity: `a.mappend(b).mappend(c) == a.mappend(b.mappend(c))`
+pub trait Semigroup {
+    /// **Signature**: mappend :: T -> T -> T
+    fn mappend(self, other: Self) -> Self;
+}
+
+/// Monoid typeclass, a [`Semigroup`] with an identity element.
+///
+/// Law:
+/// identity: `mempty().mappend(a) == a == a.mappend(mempty())`
+pub trait Monoid: Semigroup {
+    /// **Signature**: mempty :: T
+    fn mempty() -> Self;
+}
+
+/// Combine two [`Semigroup`], i.e. `<>` in Haskell
+///
+/// # Arguments
+///
+/// * `x`: [`Semigroup`] T => T
+/// * `y`: [`Semigroup`] T => T
+pub fn mappend<T: Semigroup>(x: T, y: T) -> T {
+    x.mappend(y)
+}
+
+/// Identity element of a [`Monoid`]
+pub fn mempty<T: Monoid>() -> T {
+    T::mempty()
+}
+
+/// Combine all elements of [`Iterator`]<T> from the left, return [mempty] if empty
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] T, T must impl [`Monoid`]
+pub fn mconcat<T: Monoid>(it: impl Iterator<Item=T>) -> T {
+    it.fold(T::mempty(), T::mappend)
+}
+
+#[cfg(feature = "alloc")]
+impl Semigroup for String {
+    fn mappend(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Monoid for String {
+    fn mempty() -> Self {
+        String::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for Vec<T> {
+    fn mappend(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Monoid for Vec<T> {
+    fn mempty() -> Self {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for VecDeque<T> {
+    fn mappend(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Monoid for VecDeque<T> {
+    fn mempty() -> Self {
+        VecDeque::new()
+    }
+}
+
+/// `None` acts as identity, two `Some` are combined by inner [`Semigroup`]
+impl<T: Semigroup> Semigroup for Option<T> {
+    fn mappend(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(x), Some(y)) => Some(x.mappend(y)),
+            (x, None) => x,
+            (None, y) => y,
+        }
+    }
+}
+
+impl<T: Semigroup> Monoid for Option<T> {
+    fn mempty() -> Self {
+        None
+    }
+}
+
+/// First non-`Equal` wins, useful for chaining comparators
+impl Semigroup for Ordering {
+    fn mappend(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl Monoid for Ordering {
+    fn mempty() -> Self {
+        Ordering::Equal
+    }
+}
+
+impl Semigroup for () {
+    fn mappend(self, _: Self) -> Self {}
+}
+
+impl Monoid for () {
+    fn mempty() -> Self {}
+}
+
+impl<A: Semigroup, B: Semigroup> Semigroup for (A, B) {
+    fn mappend(self, other: Self) -> Self {
+        (self.0.mappend(other.0), self.1.mappend(other.1))
+    }
+}
+
+impl<A: Monoid, B: Monoid> Monoid for (A, B) {
+    fn mempty() -> Self {
+        (A::mempty(), B::mempty())
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::hkt::Applied;
+use super::functor::Functor;
+use super::applicative::Applicative;
+use super::foldable::Foldable;
+
+/// Traversable typeclass, a container which can be walked through with an [`Applicative`] effect.
+///
+/// Laws:
+/// 1. identity: `x.traverse(Id) == Id(x)`
+/// 2. naturality: `t(x.traverse(f)) == x.traverse(compose!(t, f))` for every applicative transformation `t`
+pub trait Traversable: Functor + Foldable {
+    /// **Signature**: traverse :: T A -> (A -> G U) -> G (T U)
+    fn traverse<G, U>(self, f: impl FnMut(Self::Item) -> G) -> Applied<G, Applied<Self, U>>
+        where G: Applicative<Item=U>, U: Clone, Applied<Self, U>: Clone;
+
+    /// Turn a container of effects into an effect of container
+    ///
+    /// **Signature**: sequence :: T (G U) -> G (T U)
+    fn sequence<U>(self) -> Applied<Self::Item, Applied<Self, U>>
+        where Self::Item: Applicative<Item=U>, U: Clone, Applied<Self, U>: Clone
+    {
+        self.traverse(|x| x)
+    }
+}
+
+/// Map every element to an [`Applicative`] and collect the results inside
+///
+/// # Arguments
+///
+/// * `f`: f :: A -> G U
+/// * `ta`: [`Traversable`] T => T A
+pub fn traverse<T, G, U>(f: impl FnMut(T::Item) -> G, ta: T) -> Applied<G, Applied<T, U>>
+    where T: Traversable, G: Applicative<Item=U>, U: Clone, Applied<T, U>: Clone
+{
+    ta.traverse(f)
+}
+
+/// Turn a [`Traversable`] of [`Applicative`] inside out
+///
+/// # Arguments
+///
+/// * `ta`: [`Traversable`] T, [`Applicative`] G => T (G U)
+pub fn sequence<T, U>(ta: T) -> Applied<T::Item, Applied<T, U>>
+    where T: Traversable, T::Item: Applicative<Item=U>, U: Clone, Applied<T, U>: Clone
+{
+    ta.sequence()
+}
+
+/// Traverse a sequence of effects from right to left, so only [`Applicative::lift_a2`] is needed
+/// while the effects are still combined from the left.
+/// `prepend` collects every result into `C`, `finish` is invoked once at the leftmost step.
+#[cfg(feature = "alloc")]
+pub(crate) fn traverse_seq<G, U, C>(
+    gs: Vec<G>,
+    empty: C,
+    prepend: impl Fn(C, U) -> C,
+    finish: impl Fn(C) -> C,
+) -> Applied<G, C>
+    where G: Applicative<Item=U>, C: Clone
+{
+    let mut gs = gs.into_iter();
+    let first = match gs.next() {
+        None => return G::pure_of(finish(empty)),
+        Some(g) => g,
+    };
+    let rest = gs.rev().fold(G::pure_of(empty), |acc: Applied<G, C>, g: G| {
+        g.lift_a2(acc, |u, c| prepend(c, u))
+    });
+    first.lift_a2(rest, |u, c| finish(prepend(c, u)))
+}
+
+/// Map every element of an [`Iterator`] to an [`Applicative`] and collect the results into [`Vec`] inside,
+/// so it works on result of [map](basic_fn::fun::map) directly
+///
+/// # Arguments
+///
+/// * `f`: f :: A -> G U
+/// * `it`: [`Iterator`] A
+#[cfg(feature = "alloc")]
+pub fn traverse_iter<A, G, U>(f: impl FnMut(A) -> G, it: impl Iterator<Item=A>) -> Applied<G, Vec<U>>
+    where G: Applicative<Item=U>, U: Clone
+{
+    it.map(f).collect::<Vec<G>>().sequence()
+}
+
+/// Turn an [`Iterator`] of [`Applicative`] into an [`Applicative`] of [`Vec`]
+///
+/// # Arguments
+///
+/// * `it`: [`Iterator`] (G U)
+#[cfg(feature = "alloc")]
+pub fn sequence_iter<G, U>(it: impl Iterator<Item=G>) -> Applied<G, Vec<U>>
+    where G: Applicative<Item=U>, U: Clone
+{
+    traverse_iter(|g| g, it)
+}
@@ -0,0 +1,229 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+#[test]
+// Either combinators
+fn either() {
+    use pa::adt::Either::{self, Left, Right};
+
+    let l: Either<i32, &str> = Left(1);
+    let r: Either<i32, &str> = Right("a");
+    assert!(l.is_left() && r.is_right());
+    assert_eq!(l.left(), Some(1));
+    assert_eq!(r.left(), None);
+    assert_eq!(r.right(), Some("a"));
+    assert_eq!(l.right_or("b"), "b");
+    assert_eq!(r.left_or(0), 0);
+    assert_eq!(r.as_ref(), Right(&"a"));
+
+    assert_eq!(l.map_left(|x| x + 1), Left(2));
+    assert_eq!(l.map_right(str::len), Left(1));
+    assert_eq!(r.map_right(str::len), Right(1));
+    assert_eq!(r.bimap(|x| x * 2, |s| s.to_uppercase()), Right("A".to_string()));
+    assert_eq!(l.either(|x| x.to_string(), |s| s.to_string()), "1");
+    assert_eq!(l.flip(), Right(1));
+    assert_eq!(r.and_then(|_| Left::<i32, ()>(3)), Left(3));
+    assert_eq!(Left::<i32, i32>(5).into_inner(), 5);
+
+    let ok: Result<&str, i32> = r.into();
+    assert_eq!(ok, Ok("a"));
+    assert_eq!(Either::from(Err::<&str, i32>(1)), l);
+}
+
+#[test]
+// fn and macro either, lefts, rights and partition_eithers
+fn either_fn() {
+    use pa::adt::*;
+    use pa::adt::Either::{Left, Right};
+
+    let f = either!(|x: i32| x * 2, |s: &str| s.len() as i32);
+    assert_eq!(f(Left(2)), 4);
+    assert_eq!(f(Right("abc")), 3);
+    assert_eq!(either!(|x: i32| x, |_: ()| 0, Right(())), 0);
+
+    let es = vec![Left(1), Right("a"), Left(2), Right("b")];
+    assert_eq!(lefts(es.clone().into_iter()).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(rights(es.clone().into_iter()).collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(partition_eithers(es.into_iter()), (vec![1, 2], vec!["a", "b"]));
+}
+
+#[test]
+// Either typeclass instances are biased to Right
+fn either_instances() {
+    use pa::adt::Either::{self, Left, Right};
+    use pa::typeclass::*;
+
+    let r: Either<&str, i32> = Right(1);
+    let l: Either<&str, i32> = Left("e");
+    assert_eq!(r.fmap(|x| x + 1), Right(2));
+    assert_eq!(l.fmap(|x| x + 1), Left("e"));
+    assert_eq!(pure::<Either<&str, i32>>(1), r);
+    assert_eq!(r.ap(Right(|x| x * 3)), Right(3));
+    assert_eq!(r.lift_a2(Left::<&str, i32>("f"), |x, y| x + y), Left("f"));
+    assert_eq!(l.lift_a2(Left::<&str, i32>("f"), |x, y| x + y), Left("e"));
+    assert_eq!(r.bind(|x| if x > 0 { Right(x) } else { Left("neg") }), Right(1));
+    assert_eq!(Right::<&str, i32>(-1).bind(|x| if x > 0 { Right(x) } else { Left("neg") }), Left("neg"));
+    assert_eq!(r.foldl(10, |acc, x| acc + x), 11);
+    assert_eq!(l.length(), 0);
+    assert_eq!(r.traverse(|x| Some(x * 2)), Some(Right(2)));
+    assert_eq!(l.traverse(|x| Some(x * 2)), Some(Left("e")));
+    assert_eq!(traverse(|x: i32| if x > 0 { Right(x) } else { Left(x) }, vec![1, 2, 3]), Right::<i32, _>(vec![1, 2, 3]));
+    assert_eq!(traverse(|x: i32| if x > 0 { Right(x) } else { Left(x) }, vec![1, -2, -3]), Left::<_, Vec<i32>>(-2));
+}
+
+#[test]
+// Validated accumulates all errors
+fn validated() {
+    use pa::adt::*;
+    use pa::adt::Validated::{Valid, Invalid};
+    use pa::typeclass::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    fn check_name(name: &str) -> Validated<Vec<String>, String> {
+        validate(|n: &String| !n.is_empty(), |_| vec!["empty name".to_string()], name.to_string())
+    }
+    fn check_age(age: u32) -> Validated<Vec<String>, u32> {
+        validate(|a| *a < 150, |a| vec![format!("bad age {}", a)], age)
+    }
+    fn user(name: &str, age: u32) -> Validated<Vec<String>, User> {
+        check_name(name).lift_a2(check_age(age), |name, age| User { name, age })
+    }
+
+    assert_eq!(user("kaguya", 17), Valid(User { name: "kaguya".to_string(), age: 17 }));
+    assert_eq!(user("", 17), Invalid(vec!["empty name".to_string()]));
+    assert_eq!(user("", 200), Invalid(vec!["empty name".to_string(), "bad age 200".to_string()]));
+
+    let v: Validated<Vec<&str>, i32> = Valid(1);
+    assert_eq!(v.clone().ap(Valid(|x| x + 1)), Valid(2));
+    assert_eq!(Invalid::<Vec<&str>, i32>(vec!["x"]).ap(Invalid::<_, fn(i32) -> i32>(vec!["f"])), Invalid(vec!["f", "x"]));
+    assert_eq!(v.clone().fmap(|x| x * 2).valid(), Some(2));
+    assert_eq!(v.clone().and_then(|_| Invalid::<_, ()>(vec!["e"])).invalid(), Some(vec!["e"]));
+    assert_eq!(Validated::from(Err::<i32, _>("e")).into_result(), Err("e"));
+    assert!(v.is_valid() && !v.is_invalid());
+    assert_eq!(v.clone().map_err(|e| e.len()), Valid(1));
+    assert_eq!(v.traverse(|x| Some(x + 1)), Some(Valid(2)));
+}
+
+#[test]
+// traverse and sequence with Validated
+fn validated_traverse() {
+    use pa::adt::Validated::{self, Valid, Invalid};
+    use pa::basic_fn::fun::map;
+    use pa::typeclass::*;
+
+    fn positive(x: i32) -> Validated<Vec<i32>, i32> {
+        if x > 0 { Valid(x) } else { Invalid(vec![x]) }
+    }
+
+    assert_eq!(traverse(positive, vec![1, 2, 3]), Valid(vec![1, 2, 3]));
+    assert_eq!(traverse(positive, vec![1, -2, 3, -4]), Invalid(vec![-2, -4]));
+    assert_eq!(sequence(vec![Valid(1), Invalid(vec!["a"]), Invalid(vec!["b"])]), Invalid::<_, Vec<i32>>(vec!["a", "b"]));
+
+    assert_eq!(traverse_iter(positive, 1..4), Valid(vec![1, 2, 3]));
+    assert_eq!(sequence_iter(map(positive, vec![-1, 0, 1].into_iter())), Invalid(vec![-1, 0]));
+    assert_eq!(sequence_iter(map(|x: i32| x.checked_sub(1), 1..4)), Some(vec![0, 1, 2]));
+    assert_eq!(sequence_iter(Vec::<Validated<Vec<i32>, i32>>::new().into_iter()), Valid(vec![]));
+}
+
+#[test]
+// NonEmptyVec and nonempty!
+fn non_empty_vec() {
+    use std::convert::TryFrom;
+    use pa::adt::*;
+
+    let v = nonempty![3, 1, 4, 1, 5];
+    assert_eq!(*v.head(), 3);
+    assert_eq!(*v.last(), 5);
+    assert_eq!(v.tail(), [1, 4, 1, 5]);
+    assert_eq!(v.init(), [3, 1, 4, 1]);
+    assert_eq!(*v.max(), 5);
+    assert_eq!(*v.min(), 1);
+    assert_eq!(v.len(), 5);
+    assert!(!v.is_empty());
+    assert_eq!(v.iter().sum::<i32>(), 14);
+
+    let one = nonempty![7];
+    assert_eq!(one.head(), one.last());
+    assert!(one.tail().is_empty() && one.init().is_empty());
+
+    let mut w = NonEmptyVec::new(1);
+    assert_eq!(w.pop(), None);
+    w.push(2);
+    assert_eq!(w.pop(), Some(2));
+    assert_eq!(NonEmptyVec::from_parts(0, vec![1, 2]).into_vec(), [0, 1, 2]);
+
+    assert_eq!(NonEmptyVec::try_from(Vec::<i32>::new()), Err(EmptyError));
+    assert_eq!(NonEmptyVec::try_from(vec![1]), Ok(nonempty![1]));
+    assert_eq!(NonEmptyVec::try_from_iter(1..1), None);
+    assert_eq!(NonEmptyVec::try_from_iter(1..4), Some(nonempty![1, 2, 3]));
+
+    assert_eq!(v.clone().map(|x| x * 2), nonempty![6, 2, 8, 2, 10]);
+    assert_eq!(v.clone().fold1(|acc, x| acc - x), -8);
+    assert_eq!(nonempty!["a".to_string(), "b".to_string()].reduce(), "ab");
+    assert_eq!(Vec::from(v), [3, 1, 4, 1, 5]);
+}
+
+#[test]
+// NonEmptyList
+fn non_empty_list() {
+    use std::convert::TryFrom;
+    use pa::adt::*;
+    use pa::persistent::List;
+
+    let l = NonEmptyList::new(3).cons(2).cons(1);
+    assert_eq!(*l.head(), 1);
+    assert_eq!(*l.last(), 3);
+    assert_eq!(l.tail().iter().cloned().collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(*l.max(), 3);
+    assert_eq!(*l.min(), 1);
+    assert_eq!(l.len(), 3);
+    assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+    assert_eq!(l.map(|x| x * 10).iter().cloned().collect::<Vec<_>>(), [10, 20, 30]);
+    assert_eq!(l.fold1(|acc, x| acc * 10 + x), 123);
+    assert_eq!(NonEmptyList::new(vec![1]).cons(vec![0]).reduce(), [0, 1]);
+
+    assert!(NonEmptyList::new(1).tail().is_empty());
+    assert_eq!(NonEmptyList::try_from(List::<i32>::new()), Err(EmptyError));
+    assert_eq!(NonEmptyList::try_from_iter(vec![1, 2, 3]), Some(l.clone()));
+    assert_eq!(NonEmptyList::try_from_iter(Vec::<i32>::new()), None);
+    assert_eq!(NonEmptyList::from(nonempty![1, 2, 3]), l);
+    assert_eq!(NonEmptyList::from_parts(0, &List::new()), NonEmptyList::new(0));
+    assert_eq!(List::from(l).len(), 3);
+}
+
+#[test]
+// NonEmptyVec instances, and Validated with NonEmptyVec errors
+fn non_empty_instances() {
+    use pa::adt::*;
+    use pa::adt::Validated::{Valid, Invalid};
+    use pa::typeclass::*;
+
+    assert_eq!(nonempty![1, 2].mappend(nonempty![3]), nonempty![1, 2, 3]);
+    let l = NonEmptyList::new(2).cons(1).mappend(NonEmptyList::new(3));
+    assert_eq!(l.iter().cloned().collect::<Vec<_>>(), [1, 2, 3]);
+
+    assert_eq!(nonempty![1, 2].fmap(|x| x + 1), nonempty![2, 3]);
+    assert_eq!(pure::<NonEmptyVec<i32>>(1), nonempty![1]);
+    assert_eq!(nonempty![1, 2, 3].foldr(0, |acc, x| acc * 10 + x), 321);
+    assert_eq!(nonempty![1, 2, 3].traverse(|x| if x > 0 { Some(x) } else { None }), Some(nonempty![1, 2, 3]));
+    assert_eq!(nonempty![1, -2].traverse(|x| if x > 0 { Some(x) } else { None }), None);
+
+    fn positive(x: i32) -> Validated<NonEmptyVec<String>, i32> {
+        if x > 0 { Valid(x) } else { Invalid(nonempty![format!("{} is not positive", x)]) }
+    }
+    assert_eq!(traverse_iter(positive, vec![1, -2, 0].into_iter()),
+               Invalid(nonempty!["-2 is not positive".to_string(), "0 is not positive".to_string()]));
+    assert_eq!(nonempty![1, 2].traverse(positive), Valid(nonempty![1, 2]));
+}
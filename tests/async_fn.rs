@@ -0,0 +1,76 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "async_fn")]
+
+#[macro_use]
+extern crate pa;
+extern crate futures;
+
+use std::future::Future;
+
+use futures::executor::{block_on, LocalPool};
+use futures::future::{self, Ready};
+use futures::stream::{self, StreamExt};
+use futures::task::LocalSpawnExt;
+
+use pa::async_fn::*;
+
+#[test]
+// map, filter, filter_not, take and skip over stream
+fn map_filter_take_skip() {
+    let s = map(|x| x * 2, stream::iter(1..=5));
+    assert_eq!(block_on(s.collect::<Vec<_>>()), [2, 4, 6, 8, 10]);
+
+    let odd = filter(|x| x % 2 == 1, stream::iter(1..10));
+    assert_eq!(block_on(odd.collect::<Vec<_>>()), [1, 3, 5, 7, 9]);
+    let even = filter_not(|x| x % 2 == 1, stream::iter(1..10));
+    assert_eq!(block_on(even.collect::<Vec<_>>()), [2, 4, 6, 8]);
+
+    let middle = take(3, skip(2, stream::iter(0..)));
+    assert_eq!(block_on(middle.collect::<Vec<_>>()), [2, 3, 4]);
+}
+
+#[test]
+// foldl, zip, zip_with, scan and group_by over stream
+fn fold_zip_scan_group() {
+    assert_eq!(block_on(foldl(1, |acc, x| acc * x, stream::iter(1..=5))), 120);
+
+    let pairs = zip(stream::iter(vec!["Houraisan", "Kaguya"]), stream::iter(0..));
+    assert_eq!(block_on(pairs.collect::<Vec<_>>()), [("Houraisan", 0), ("Kaguya", 1)]);
+    let sums = zip_with(|a, b| a + b, stream::iter(1..4), stream::iter(10..));
+    assert_eq!(block_on(sums.collect::<Vec<_>>()), [11, 13, 15]);
+
+    let running = scan(0, |acc, x| acc + x, stream::iter(1..5));
+    assert_eq!(block_on(running.collect::<Vec<_>>()), [0, 1, 3, 6, 10]);
+
+    let groups = block_on(group_by(|x| x % 3, stream::iter(0..10)));
+    assert_eq!(groups[&0], [0, 3, 6, 9]);
+    assert_eq!(groups[&2], [2, 5, 8]);
+}
+
+fn add_one(x: i32) -> Ready<i32> {
+    future::ready(x + 1)
+}
+
+fn sum_stream(n: i32) -> impl Future<Output=i32> {
+    foldl(0, |acc, x| acc + x, stream::iter(0..n))
+}
+
+#[test]
+// async_pipe! and async_compose! chain async functions on a local executor
+fn async_pipe_compose() {
+    let f = async_pipe!(add_one, sum_stream, |x: i32| future::ready(x * 2));
+    assert_eq!(block_on(f(3)), 12);
+
+    let g = async_compose!(|x: i32| future::ready(x.to_string()), add_one, add_one);
+    assert_eq!(block_on(g(1)), "3");
+
+    let mut pool = LocalPool::new();
+    let h = pool.spawner().spawn_local_with_handle(async_pipe!(sum_stream)(5)).unwrap();
+    assert_eq!(pool.run_until(h), 10);
+}
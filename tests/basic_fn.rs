@@ -4,10 +4,12 @@
 // http://www.apache.org/licenses/LICENSE-2.0>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-#![feature(trace_macros)]
+#![allow(clippy::useless_vec, clippy::map_clone, clippy::assertions_on_constants, clippy::needless_lifetimes)]
 
 #[macro_use]
 extern crate pa;
+#[macro_use]
+extern crate proptest;
 
 #[test]
 /// macro compose!
@@ -154,6 +156,62 @@ fn foldr() {
     assert_eq!(step_curry_2(v.iter()), "楽しい〜 Kaguya Houraisan");
 }
 
+#[test]
+#[cfg(feature = "std")]
+// fn fold_while, foldl_by, foldr_by and their curry macros
+fn fold_while_and_by() {
+    use std::ops::ControlFlow;
+    use pa::basic_fn::fun::{fold_while, foldl_by, foldr_by};
+    let sum_under = |acc, x| if acc + x > 10 { ControlFlow::Break(acc) } else { ControlFlow::Continue(acc + x) };
+    assert_eq!(fold_while(0, sum_under, 1..), 10);
+    assert_eq!(fold_while(0, sum_under, 1..4), 6);
+    let curry = fold_while!(0=>);
+    assert_eq!(curry(sum_under)(1..), 10);
+
+    let words = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+    let joined = foldl_by(String::new(), |acc, w| acc + w, |w| w.chars().next(), words.iter());
+    assert_eq!(joined[&Some('a')], "appleavocado");
+    assert_eq!(joined[&Some('c')], "cherry");
+    let joined = foldr_by(String::new(), |acc, w| acc + w, |w| w.chars().next(), words.iter());
+    assert_eq!(joined[&Some('b')], "blueberrybanana");
+
+    let count = foldl_by!(0, |acc, _| acc + 1, |x: &i32| x % 3);
+    let counted = count(0..10);
+    assert_eq!((counted[&0], counted[&1], counted[&2]), (4, 3, 3));
+    let lengths = foldr_by!(0);
+    assert_eq!(lengths(|acc, w: &&str| acc + w.len(), |w: &&&str| w.len() > 5, words.iter())[&true], 28);
+}
+
+#[test]
+// fn scan, map_accum, map_accum_right, unfold and their curry macros
+fn accumulating_traversals() {
+    use pa::basic_fn::fun::{scan, map_accum, map_accum_right, unfold};
+    assert_eq!(scan(0, |acc, x| acc + x, 1..5).collect::<Vec<_>>(), [0, 1, 3, 6, 10]);
+    assert_eq!(scan(1, |acc, x| acc * x, 1..).nth(5), Some(120));
+    assert_eq!(scan(0, |acc: i32, x: i32| acc + x, 0..0).collect::<Vec<_>>(), [0]);
+    let running = scan!(0, |acc, x| acc + x);
+    assert_eq!(running(vec![3, 4].into_iter()).collect::<Vec<_>>(), [0, 3, 7]);
+
+    let digits = vec![1, 2, 3];
+    let (total, prefix) = map_accum(0, |acc, x| (acc + x, acc + x), digits.iter());
+    assert_eq!((total, prefix), (6, vec![1, 3, 6]));
+    let (total, suffix) = map_accum_right(0, |acc, x| (acc + x, acc + x), digits.iter());
+    assert_eq!((total, suffix), (6, vec![6, 5, 3]));
+    let label = map_accum!(String::new());
+    let (all, labels) = label(|acc: String, x: &i32| (acc.clone() + &x.to_string(), format!("{}:{}", acc, x)), digits.iter());
+    assert_eq!(all, "123");
+    assert_eq!(labels, [":1", "1:2", "12:3"]);
+    let right = map_accum_right!(1=>);
+    assert_eq!(right(|acc, x| (acc * x, acc))(digits.iter()), (6, vec![6, 3, 1]));
+
+    let fib = unfold((0u64, 1u64), |(a, b)| Some((a, (b, a + b))));
+    assert_eq!(fib.take(6).collect::<Vec<_>>(), [0, 1, 1, 2, 3, 5]);
+    let countdown = unfold!(3, |n| if n == 0 { None } else { Some((n, n - 1)) });
+    assert_eq!(countdown.collect::<Vec<_>>(), [3, 2, 1]);
+    let from = unfold!(1);
+    assert_eq!(from(|n| if n > 100 { None } else { Some((n, n * 2)) }).collect::<Vec<_>>(), [1, 2, 4, 8, 16, 32, 64]);
+}
+
 #[test]
 // fn sum and macro sum
 fn sum() {
@@ -191,12 +249,18 @@ fn head() {
 #[test]
 // fn tail
 fn tail() {
-    use pa::basic_fn::fun::tail;
+    use pa::basic_fn::fun::{tail, tail_vec};
     let empty_vec = Vec::new() as Vec<i8>;
     let vec = vec![1, 2, 3];
 
-    assert_eq!(None, tail(empty_vec.iter()));
-    assert_eq!(Some(vec![&2, &3]), tail(vec.iter()));
+    assert!(tail(empty_vec.iter()).is_none());
+    assert_eq!(Some(vec![&2, &3]), tail(vec.iter()).map(|it| it.collect()));
+    assert_eq!(tail(vec.iter()).map(|it| it.rev().len()), Some(2));
+    // lazy on infinite iterator
+    assert_eq!(tail(0..).map(|it| it.take(2).collect()), Some(vec![1, 2]));
+
+    assert_eq!(None, tail_vec(empty_vec.iter()));
+    assert_eq!(Some(vec![&2, &3]), tail_vec(vec.iter()));
 }
 
 #[test]
@@ -213,42 +277,63 @@ fn last() {
 #[test]
 // fn init
 fn init() {
-    use pa::basic_fn::fun::init;
+    use pa::basic_fn::fun::{init, init_vec};
     let empty_vec = Vec::new() as Vec<i8>;
     let vec = vec![1,2,3];
 
-    assert_eq!(None, init(empty_vec.iter()));
-    assert_eq!(Some(vec![&1,&2]), init(vec.iter()));
+    assert!(init(empty_vec.iter()).is_none());
+    assert_eq!(Some(vec![&1,&2]), init(vec.iter()).map(|it| it.collect()));
+    assert_eq!(Some(vec![]), init(Some(1).into_iter()).map(|it| it.collect()));
+    // lazy on infinite iterator
+    assert_eq!(init(0..).map(|it| it.take(3).collect()), Some(vec![0, 1, 2]));
+    // double ended and exact size
+    let it = init(vec.iter()).unwrap();
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.rev().collect::<Vec<_>>(), vec![&2, &1]);
+    let mut it = init(1..=5).unwrap();
+    assert_eq!((it.next(), it.next_back(), it.next_back(), it.next(), it.next()), (Some(1), Some(4), Some(3), Some(2), None));
+    assert_eq!(it.next_back(), None);
+
+    assert_eq!(None, init_vec(empty_vec.iter()));
+    assert_eq!(Some(vec![&1,&2]), init_vec(vec.iter()));
 }
 
 #[test]
 // fn and macro skip
 fn skip() {
-    use pa::basic_fn::fun::skip;
+    use pa::basic_fn::fun::{skip, skip_vec};
     let empty_vec = Vec::new() as Vec<i8>;
     let vec = vec![1,2,3];
 
-    assert_eq!(Vec::new() as Vec<&i8>, skip(1,empty_vec.iter()));
-    assert_eq!(vec![&2,&3], skip(1, vec.iter()));
+    assert_eq!(Vec::new() as Vec<&i8>, skip(1,empty_vec.iter()).collect::<Vec<_>>());
+    assert_eq!(vec![&2,&3], skip(1, vec.iter()).collect::<Vec<_>>());
+    assert_eq!(vec![&3,&2], skip(1, vec.iter()).rev().collect::<Vec<_>>());
+    assert_eq!(skip(1, 0..).next(), Some(1));
 
     let curry = skip!(1);
-    assert_eq!((Vec::new() as Vec<&i8>), curry(empty_vec.iter()));
-    assert_eq!(vec![&2, &3], curry(vec.iter()));
+    assert_eq!((Vec::new() as Vec<&i8>), curry(empty_vec.iter()).collect::<Vec<_>>());
+    assert_eq!(vec![&2, &3], curry(vec.iter()).collect::<Vec<_>>());
+
+    assert_eq!(vec![&2,&3], skip_vec(1, vec.iter()));
 }
 
 #[test]
 // fn and macro take
 fn take() {
-    use pa::basic_fn::fun::take;
+    use pa::basic_fn::fun::{take, take_vec};
     let empty_rec = Vec::new() as Vec<i8>;
     let vec = vec![1,2,3];
 
-    assert_eq!(Vec::new() as Vec<&i8>, take(2, empty_rec.iter()));
-    assert_eq!(vec![&1,&2], take(2, vec.iter()));
+    assert_eq!(Vec::new() as Vec<&i8>, take(2, empty_rec.iter()).collect::<Vec<_>>());
+    assert_eq!(vec![&1,&2], take(2, vec.iter()).collect::<Vec<_>>());
+    assert_eq!(take(2, vec.iter()).len(), 2);
+    assert_eq!(vec![0,1,2], take(3, 0..).collect::<Vec<_>>());
 
     let curry = take!(2);
-    assert_eq!(Vec::new() as Vec<&i8>, curry(empty_rec.iter()));
-    assert_eq!(vec![&1,&2], curry(vec.iter()));
+    assert_eq!(Vec::new() as Vec<&i8>, curry(empty_rec.iter()).collect::<Vec<_>>());
+    assert_eq!(vec![&1,&2], curry(vec.iter()).collect::<Vec<_>>());
+
+    assert_eq!(vec![&1,&2], take_vec(2, vec.iter()));
 }
 
 #[test]
@@ -272,17 +357,23 @@ fn length() {
 #[test]
 // fn reverse
 fn reverse() {
-    use pa::basic_fn::fun::reverse;
-    assert_eq!(reverse(1..=5), vec![5,4,3,2,1]);
+    use pa::basic_fn::fun::{reverse, reverse_vec};
+    assert_eq!(reverse(1..=5).collect::<Vec<_>>(), vec![5,4,3,2,1]);
+    assert_eq!(reverse_vec(1..=5), vec![5,4,3,2,1]);
+    // single ended
+    assert_eq!(reverse_vec(std::iter::successors(Some(1), |x| if *x < 5 { Some(x + 2) } else { None })), vec![5,3,1]);
 }
 
 #[test]
 // fn and macro concat
 fn concat() {
-    use pa::basic_fn::fun::concat;
-    assert_eq!(concat(0..1,1..2), vec![0,1]);
+    use pa::basic_fn::fun::{concat, concat_vec};
+    assert_eq!(concat(0..1,1..2).collect::<Vec<_>>(), vec![0,1]);
+    assert_eq!(concat(0..2,2..).take(4).collect::<Vec<_>>(), vec![0,1,2,3]);
+    assert_eq!(concat(0..1,1..3).rev().collect::<Vec<_>>(), vec![2,1,0]);
+    assert_eq!(concat_vec(0..1,1..2), vec![0,1]);
     assert_eq!(
-        concat!(0..1;1..2;2..3;3..=4;vec![5,6].iter()),
+        concat_vec!(0..1;1..2;2..3;3..=4;vec![5,6].iter()),
         vec![0,1,2,3,4,5,6]
     );
 }
@@ -367,6 +458,17 @@ fn odd_and_even() {
     let f2 = odd!(>i32);
     assert!(f1(2));
     assert!(f2(1));
+    assert!(f2(-3));
+    assert!(f1(-4));
+}
+
+#[test]
+// macro recip
+fn recip() {
+    assert_eq!(recip!(4.0), 0.25);
+    let f = recip!(>f64);
+    assert_eq!(f(0.5), 2.0);
+    assert!(f(0.0).is_infinite());
 }
 
 #[test]
@@ -465,6 +567,8 @@ fn zip() {
     let ls1 = vec![1,2,3];
     let ls2 = vec!['a','b','c'];
     assert_eq!(zip(ls1.iter(), ls2.iter()).map(|(x,y)| (*x,*y)).collect::<Vec<_>>(), vec![(1,'a'), (2,'b'), (3,'c')]);
+    assert_eq!(zip(0.., ls2.iter()).count(), 3);
+    assert_eq!(zip(ls1.iter(), ls2.iter()).len(), 3);
 
     let it = ls1.iter();
     let mac = zip!(it);
@@ -486,4 +590,253 @@ fn zip_with() {
     assert_eq!(mac1(ls1.iter(), ls2.iter()).collect::<Vec<_>>(), vec![false, true, false]);
     assert_eq!(mac2(ls1.iter())(ls2.iter()).collect::<Vec<_>>(), vec![false, true, false]);
     assert_eq!(mac3(ls2.iter()).collect::<Vec<_>>(), vec![false, true, false]);
-}
\ No newline at end of file
+}
+
+#[test]
+// macro partial!
+fn partial() {
+    use pa::basic_fn::fun::*;
+
+    let rem3 = partial!(rem, _, 3);
+    assert_eq!(rem3(10), 1);
+    assert_eq!(rem3(11), 2);
+
+    let ten_rem = partial!(rem, 10, _);
+    assert_eq!(ten_rem(4), 2);
+
+    let sum = partial!(foldl, 0, |acc, x| acc + x, _);
+    assert_eq!(sum(1..5), 10);
+
+    let f = partial!(foldl, 1, _, _,);
+    assert_eq!(f(|acc, x| acc * x, 1..5), 24);
+
+    let g = partial!(zip_with, _, 0..3, _);
+    assert_eq!(g(|(x, y)| x + y, 10..).collect::<Vec<_>>(), [10, 12, 14]);
+
+    let h = partial!(sub, 3, 1);
+    assert_eq!(h(), 2);
+
+    let skip2 = partial!(skip_vec, 2, _);
+    assert_eq!(skip2(vec![1, 2, 3].into_iter()), [3]);
+
+    // arguments are bound once, non-Copy ones are cloned into every call
+    fn pushed(mut v: Vec<i32>, x: i32) -> Vec<i32> {
+        v.push(x);
+        v
+    }
+    let push = partial!(pushed, vec![1, 2], _);
+    assert_eq!(push(3), [1, 2, 3]);
+    assert_eq!(push(4), [1, 2, 4]);
+
+    let calls = std::cell::Cell::new(0);
+    let inc = partial!(add, { calls.set(calls.get() + 1); 1 }, _);
+    assert_eq!(inc(1) + inc(2), 5);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+// fn and macro flip
+fn flip() {
+    use pa::basic_fn::fun::*;
+
+    let f = flip(rem);
+    assert_eq!(f(3, 10), 1);
+    let f = flip(concat_vec);
+    assert_eq!(f(vec![3].into_iter(), vec![1, 2].into_iter()), [1, 2, 3]);
+
+    let f = flip!(sub);
+    assert_eq!(f(1, 3), 2);
+    let f = flip!(rem, 3);
+    assert_eq!(f(10), 1);
+    assert_eq!(flip!(sub, 1, 3), 2);
+
+    let f = flip!(foldl, |acc: i32, x| acc - x, 0, _);
+    assert_eq!(f(1..4), -6);
+}
+
+#[test]
+// ls! filterer borrows item, so non-Copy items work
+fn ls_non_copy() {
+    let words = vec!["a".to_string(), "bb".to_string(), "ccc".to_string()];
+    assert_eq!(ls![|s: String| s.len(); words => |s: &String| s.len() != 2], vec![1, 3]);
+}
+
+#[test]
+// ls! comprehension with generators, bindings and guards
+fn ls_comprehension() {
+    assert_eq!(ls![x * 2; x <- 1..4], vec![2, 4, 6]);
+    assert_eq!(ls![(x, y); x <- 1..4, y <- "ab".chars(), x % 2 == 1],
+               vec![(1, 'a'), (1, 'b'), (3, 'a'), (3, 'b')]);
+    assert_eq!(ls![(x, y); x <- 1..5, y <- x..5, x + y == 5], vec![(1, 4), (2, 3)]);
+    assert_eq!(ls![z; x <- 1..4, let z = x * x, z > 1, z < 9], vec![4]);
+    assert_eq!(ls![x; x <- vec![-2, -1, 0, 1], x < (-1)], vec![-2]);
+
+    // pattern destructuring, unmatched items are skipped
+    let pairs = vec![(1, "one"), (2, "two")];
+    assert_eq!(ls![format!("{}={}", n, s); &(n, s) <- &pairs, n > 1], vec!["2=two".to_string()]);
+    let opts = vec![Some(1), None, Some(3)];
+    assert_eq!(ls![x; Some(x) <- opts], vec![1, 3]);
+
+    // pythagorean triples
+    assert_eq!(ls![(a, b, c); c <- 1..14, b <- 1..c, a <- 1..b, a * a + b * b == c * c],
+               vec![(3, 4, 5), (6, 8, 10), (5, 12, 13)]);
+}
+
+#[test]
+// ls! lazy comprehension
+fn ls_lazy() {
+    let it = ls![iter x * x; x <- 1.., x % 3 == 0];
+    assert_eq!(it.take(3).collect::<Vec<_>>(), vec![9, 36, 81]);
+
+    let ys = vec![10, 20];
+    let ys = &ys;
+    let it = ls![iter s; x <- 1..3, &y <- ys, let s = x + y];
+    assert_eq!(it.collect::<Vec<_>>(), vec![11, 21, 12, 22]);
+
+    let it = ls![iter c; (i, c) <- "abc".chars().enumerate(), i != 1];
+    assert_eq!(it.collect::<String>(), "ac");
+}
+
+#[test]
+// converge! feeds one input to every branch
+fn converge() {
+    let avg = converge!(|s: i32, n: usize| s / n as i32, |v: Vec<i32>| v.iter().sum::<i32>(), |v: Vec<i32>| v.len());
+    assert_eq!(avg(vec![1, 2, 3, 6]), 3);
+    let f = converge!(|a, b| (a, b), |x: &str| x.len(), |x: &str| x.to_uppercase());
+    assert_eq!(f("ab"), (2, "AB".to_string()));
+    let g = pipe!(|x: i32| x + 1, converge!(|a, b, c| a + b + c, |x| x, |x| x * 2, |x| x * 3));
+    assert_eq!(g(1), 12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+// group_by, group_with and partition
+fn group_and_partition() {
+    use pa::basic_fn::fun::{group_by, group_by_ord, group_with, partition};
+    use std::collections::BTreeMap;
+
+    let words = vec!["apple", "bob", "avocado", "cat", "banana"];
+    let by_initial = group_by(|w: &&str| w.chars().next(), words.clone().into_iter());
+    assert_eq!(by_initial[&Some('a')], vec!["apple", "avocado"]);
+    assert_eq!(by_initial.len(), 3);
+    let by_len: BTreeMap<usize, Vec<&str>> = group_by_ord(|w: &&str| w.len(), words.clone().into_iter());
+    assert_eq!(by_len.keys().cloned().collect::<Vec<_>>(), vec![3, 5, 6, 7]);
+    assert_eq!(group_by!(|x: &i32| x % 2)(1..6)[&1], vec![1, 3, 5]);
+
+    let runs = group_with(|a: &i32, b: &i32| b - a == 1, vec![1, 2, 3, 5, 6, 8].into_iter());
+    assert_eq!(runs.collect::<Vec<_>>(), vec![vec![1, 2, 3], vec![5, 6], vec![8]]);
+    let same = group_with!(|a: &char, b: &char| a == b);
+    assert_eq!(same("aabccc".chars()).map(|g| g.len()).collect::<Vec<_>>(), vec![2, 1, 3]);
+    assert_eq!(group_with(|_: &i32, _: &i32| true, 0..0).next(), None);
+    // lazy on infinite iterator
+    assert_eq!(group_with!(|a: &u32, b: &u32| a / 3 == b / 3, 0..).nth(2), Some(vec![6, 7, 8]));
+
+    let (even, odd): (Vec<i32>, Vec<i32>) = partition(|x: &i32| x % 2 == 0, 1..6);
+    assert_eq!((even, odd), (vec![2, 4], vec![1, 3, 5]));
+    let (short, long): (Vec<&str>, Vec<&str>) = partition!(|w: &&str| w.len() < 4, words.into_iter());
+    assert_eq!(short, vec!["bob", "cat"]);
+    assert_eq!(long.len(), 3);
+}
+
+#[test]
+// split_at, split_every and split_when
+fn split() {
+    use pa::basic_fn::fun::{split_at, split_every, split_when, take_vec};
+
+    let (first, rest) = split_at(2, 1..);
+    assert_eq!(first, vec![1, 2]);
+    assert_eq!(take_vec(2, rest), vec![3, 4]);
+    let (first, rest) = split_at!(5, vec![1, 2].into_iter());
+    assert_eq!((first, rest.count()), (vec![1, 2], 0));
+
+    assert_eq!(split_every(2, 1..6).collect::<Vec<_>>(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+    assert_eq!(split_every(2, 1..6).size_hint(), (3, Some(3)));
+    assert_eq!(split_every!(3)("abcdefg".chars()).map(|c| c.into_iter().collect::<String>()).collect::<Vec<_>>(),
+               vec!["abc", "def", "g"]);
+
+    let (before, after) = split_when(|x: &i32| *x == 3, vec![1, 2, 3, 1, 2].into_iter());
+    assert_eq!((before, after.collect::<Vec<_>>()), (vec![1, 2], vec![3, 1, 2]));
+    let (before, after) = split_when!(|x: &i32| *x > 100)(1..4);
+    assert_eq!((before, after.count()), (vec![1, 2, 3], 0));
+}
+
+#[test]
+#[cfg(feature = "std")]
+// set functions keep order of first appearance
+fn set_functions() {
+    use pa::basic_fn::fun::{unique, unique_ord, unique_by, unique_with, union, union_ord, union_with,
+                            intersection, intersection_with, diff, diff_ord, diff_with, without, without_ord, take_vec};
+
+    let a = vec![3, 1, 3, 2, 1];
+    let b = vec![2, 4, 2];
+    assert_eq!(unique(a.clone().into_iter()).collect::<Vec<_>>(), vec![3, 1, 2]);
+    assert_eq!(unique_ord(a.clone().into_iter()).collect::<Vec<_>>(), vec![3, 1, 2]);
+    assert_eq!(unique_by(|x: &i32| x % 2, a.clone().into_iter()).collect::<Vec<_>>(), vec![3, 2]);
+    assert_eq!(unique_with(|x: &f64, y: &f64| (x - y).abs() < 0.1, vec![1.0, 1.05, 2.0].into_iter()), vec![1.0, 2.0]);
+    assert_eq!(union(a.clone().into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), vec![3, 1, 2, 4]);
+    assert_eq!(union_ord(b.clone().into_iter(), a.clone().into_iter()).collect::<Vec<_>>(), vec![2, 4, 3, 1]);
+    assert_eq!(union_with(|x: &&str, y: &&str| x.eq_ignore_ascii_case(y), vec!["a", "B"].into_iter(), vec!["b", "c"].into_iter()),
+               vec!["a", "B", "c"]);
+    assert_eq!(intersection(a.clone().into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(intersection_with(|x: &i32, y: &i32| x % 3 == y % 3, a.clone().into_iter(), b.clone().into_iter()), vec![1, 2]);
+    assert_eq!(diff(a.clone().into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), vec![3, 1]);
+    assert_eq!(diff_ord(b.clone().into_iter(), a.clone().into_iter()).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(diff_with(|x: &i32, y: &i32| x % 3 == y % 3, a.clone().into_iter(), b.clone().into_iter()), vec![3]);
+    assert_eq!(without(vec![1, 2].into_iter(), a.clone().into_iter()).collect::<Vec<_>>(), vec![3, 3]);
+    assert_eq!(without_ord(vec![3].into_iter(), a.clone().into_iter()).collect::<Vec<_>>(), vec![1, 2, 1]);
+
+    // lazy on infinite iterator
+    assert_eq!(take_vec(3, union(vec![5, 0].into_iter(), 0..)), vec![5, 0, 1]);
+    assert_eq!(take_vec(3, diff(0.., vec![1, 3].into_iter())), vec![0, 2, 4]);
+
+    // macros
+    assert_eq!(without!(vec![1].into_iter())(a.clone().into_iter()).count(), 3);
+    assert_eq!(union!(vec![0].into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), vec![0, 2, 4]);
+    let (a2, b2) = (a.clone(), b.clone());
+    assert_eq!(diff!(a2.into_iter())(b.clone().into_iter()).collect::<Vec<_>>(), vec![3, 1]);
+    assert_eq!(intersection!(b2.into_iter())(a.clone().into_iter()).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(unique_by!(|s: &&str| s.len())(vec!["a", "bb", "c"].into_iter()).collect::<Vec<_>>(), vec!["a", "bb"]);
+    assert_eq!(unique_with!(|x: &i32, y: &i32| x == y, b.clone().into_iter()), vec![2, 4]);
+    assert_eq!(union_with!(|x: &i32, y: &i32| x == y)(a.into_iter(), b.into_iter()), vec![3, 1, 2, 4]);
+}
+
+proptest! {
+    #[test]
+    #[cfg(feature = "std")]
+    // set laws of union, intersection and diff
+    fn set_laws(a in proptest::collection::vec(0u8..16, 0..24), b in proptest::collection::vec(0u8..16, 0..24)) {
+        use std::collections::BTreeSet;
+        use pa::basic_fn::fun::{unique, unique_with, union, union_ord, intersection, intersection_ord, diff, diff_with, without};
+
+        let set = |v: Vec<u8>| v.into_iter().collect::<BTreeSet<_>>();
+        let (sa, sb) = (set(a.clone()), set(b.clone()));
+        let u: Vec<u8> = union(a.clone().into_iter(), b.clone().into_iter()).collect();
+        let i: Vec<u8> = intersection(a.clone().into_iter(), b.clone().into_iter()).collect();
+        let d: Vec<u8> = diff(a.clone().into_iter(), b.clone().into_iter()).collect();
+
+        // no duplication
+        prop_assert_eq!(set(u.clone()).len(), u.len());
+        prop_assert_eq!(set(i.clone()).len(), i.len());
+        prop_assert_eq!(set(d.clone()).len(), d.len());
+        // same as std sets
+        prop_assert_eq!(set(u.clone()), sa.union(&sb).cloned().collect::<BTreeSet<_>>());
+        prop_assert_eq!(set(i.clone()), sa.intersection(&sb).cloned().collect::<BTreeSet<_>>());
+        prop_assert_eq!(set(d.clone()), sa.difference(&sb).cloned().collect::<BTreeSet<_>>());
+        // commutative
+        prop_assert_eq!(set(u.clone()), set(union(b.clone().into_iter(), a.clone().into_iter()).collect()));
+        prop_assert_eq!(set(i.clone()), set(intersection(b.clone().into_iter(), a.clone().into_iter()).collect()));
+        // diff and intersection split unique(a)
+        let ua: Vec<u8> = unique(a.clone().into_iter()).collect();
+        prop_assert_eq!(set(union(d.clone().into_iter(), i.clone().into_iter()).collect()), set(ua.clone()));
+        // order of first appearance
+        prop_assert_eq!(u.iter().take(ua.len()).cloned().collect::<Vec<_>>(), ua.clone());
+        prop_assert_eq!(unique_with(|x: &u8, y: &u8| x == y, a.clone().into_iter()), ua);
+        // variants agree
+        prop_assert_eq!(union_ord(a.clone().into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), u);
+        prop_assert_eq!(intersection_ord(a.clone().into_iter(), b.clone().into_iter()).collect::<Vec<_>>(), i);
+        prop_assert_eq!(diff_with(|x: &u8, y: &u8| x == y, a.clone().into_iter(), b.clone().into_iter()), d);
+        // without keeps the rest with duplication
+        let w: Vec<u8> = without(b.clone().into_iter(), a.clone().into_iter()).collect();
+        prop_assert_eq!(w, a.iter().cloned().filter(|x| !sb.contains(x)).collect::<Vec<_>>());
+    }
+}
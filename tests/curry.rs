@@ -0,0 +1,92 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate pa;
+
+use pa::curry;
+
+#[curry]
+fn foldl<T, R>(init: R, f: impl Fn(R, T) -> R, it: impl Iterator<Item=T>) -> R {
+    it.fold(init, f)
+}
+
+#[curry]
+fn add3(x: i32, y: i32, z: i32) -> i32 {
+    if x == 0 {
+        return y + z;
+    }
+    x + y + z
+}
+
+#[curry]
+fn map<T, U>(f: impl Fn(T) -> U, it: impl Iterator<Item=T>) -> impl Iterator<Item=U> {
+    it.map(f)
+}
+
+#[curry]
+fn prefix(pre: &str, s: &str) -> String {
+    format!("{}{}", pre, s)
+}
+
+#[curry(sub_c)]
+fn sub(x: i32, y: i32) -> i32 {
+    x - y
+}
+
+#[curry(take_c)]
+fn take<I: Iterator>(n: usize, it: I) -> std::iter::Take<I> {
+    it.take(n)
+}
+
+#[curry]
+fn id<T>(x: T) -> T {
+    x
+}
+
+#[test]
+// curried call
+fn curry() {
+    assert_eq!(foldl(0)(|acc, x| acc + x)(1..5), 10);
+    assert_eq!(add3(1)(2)(3), 6);
+    assert_eq!(add3(0)(2)(3), 5);
+    assert_eq!(id(1), 1);
+}
+
+#[test]
+// partial application can be reused
+fn partial() {
+    let sum = foldl(0)(|acc, x| acc + x);
+    assert_eq!(sum(1..5), 10);
+    assert_eq!(sum(5..7), 11);
+
+    let add1 = add3(1);
+    let add3_ = add1(2);
+    assert_eq!(add3_(3), 6);
+    assert_eq!(add3_(4), 7);
+    assert_eq!(add1(0)(0), 1);
+
+    let inc = map(|x: i32| x + 1);
+    assert_eq!(inc(1..4).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(inc(10..11).collect::<Vec<_>>(), [11]);
+}
+
+#[test]
+// borrowed arguments
+fn borrowed() {
+    let hello = String::from("hello, ");
+    let greet = prefix(&hello);
+    assert_eq!(greet("world"), "hello, world");
+}
+
+#[test]
+// original function is kept with companion name
+fn companion() {
+    assert_eq!(sub(3, 1), 2);
+    assert_eq!(sub_c(3)(1), 2);
+    assert_eq!(take(2, 1..).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(take_c(2)(1..).collect::<Vec<_>>(), [1, 2]);
+}
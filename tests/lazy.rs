@@ -0,0 +1,152 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate pa;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use pa::lazy::*;
+
+#[test]
+// Stream is memoised and can be traversed more than once
+fn stream_memoised() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let squares = iterate(1u64, |x| x + 1).map(move |x| {
+        counter.set(counter.get() + 1);
+        x * x
+    });
+    assert_eq!(calls.get(), 0);
+    assert_eq!(squares.iter().take(3).cloned().collect::<Vec<_>>(), vec![1, 4, 9]);
+    assert_eq!(calls.get(), 3);
+    let shared = squares.clone();
+    assert_eq!(shared.iter().take(4).cloned().collect::<Vec<_>>(), vec![1, 4, 9, 16]);
+    assert_eq!(squares.iter().take(4).sum::<u64>(), 30);
+    assert_eq!(calls.get(), 4);
+    assert_eq!(format!("{:?}", squares), "[1, 4, 9, 16, ..]");
+
+    assert_eq!(squares.head(), Some(&1));
+    assert_eq!(squares.tail().and_then(|t| t.head().cloned()), Some(4));
+    assert!(Stream::<i32>::empty().is_empty());
+    assert_eq!(Stream::cons(1, Stream::empty()).into_iter().collect::<Vec<_>>(), vec![1]);
+
+    // long streams are dropped without overflow
+    let long = iterate(0u32, |x| x + 1);
+    assert_eq!(long.iter().nth(1_000_000), Some(&1_000_000));
+}
+
+#[test]
+// Stream constructors
+fn stream_constructors() {
+    let fib = unfold((0u32, 1u32), |(a, b)| Some((a, (b, a + b))));
+    assert_eq!(fib.take(7).into_iter().collect::<Vec<_>>(), vec![0, 1, 1, 2, 3, 5, 8]);
+    assert_eq!(repeat('a').take(3).into_iter().collect::<String>(), "aaa");
+    assert_eq!(cycle(vec![1, 2, 3]).iter().take(7).cloned().collect::<Vec<_>>(), vec![1, 2, 3, 1, 2, 3, 1]);
+    assert!(cycle(Vec::<i32>::new()).is_empty());
+    assert_eq!(times(4, |i| i * 10).into_iter().collect::<Vec<_>>(), vec![0, 10, 20, 30]);
+    assert_eq!((1..4).collect::<Stream<_>>().into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let from = Stream::from_iterator((0..).map(|x| x * 2));
+    assert_eq!(from.iter().nth(3), Some(&6));
+}
+
+#[test]
+// Stream combinators
+fn stream_combinators() {
+    let nat = iterate(0, |x| x + 1);
+    let firsts = |s: Stream<i32>, n| s.iter().take(n).cloned().collect::<Vec<_>>();
+    assert_eq!(firsts(nat.filter(|x| x % 3 == 0), 3), vec![0, 3, 6]);
+    assert_eq!(firsts(nat.take_while(|x| *x < 4), 10), vec![0, 1, 2, 3]);
+    assert_eq!(firsts(nat.scan(0, |acc, x| acc + x), 5), vec![0, 0, 1, 3, 6]);
+    assert_eq!(firsts(nat.zip_with(&nat.map(|x| x * 10), |x, y| x + y), 3), vec![0, 11, 22]);
+    assert_eq!(nat.zip(&cycle("ab".chars())).iter().take(3).cloned().collect::<Vec<_>>(),
+               vec![(0, 'a'), (1, 'b'), (2, 'a')]);
+    assert_eq!(firsts(nat.take(2).interleave(&repeat(-1)), 6), vec![0, -1, 1, -1, -1, -1]);
+
+    // works with functions of basic_fn
+    use pa::basic_fn::fun::{sum, take, filter};
+    assert_eq!(sum::<i32>(take(4, filter(|x: &i32| x % 2 == 1, nat.clone().into_iter()))), 16);
+}
+
+#[test]
+// Lazy and Eval strategies
+fn eval() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let lazy = Lazy::new(move || {
+        counter.set(counter.get() + 1);
+        42
+    });
+    let shared = lazy.clone();
+    assert_eq!(calls.get(), 0);
+    assert_eq!(*shared.force(), 42);
+    assert_eq!(*lazy + 1, 43);
+    assert_eq!(calls.get(), 1);
+
+    let counter = calls.clone();
+    let always = Eval::always(move || {
+        counter.set(counter.get() + 1);
+        counter.get()
+    });
+    assert_eq!(always.value(), 2);
+    assert_eq!(always.clone().value(), 3);
+    let later = always.memoize();
+    assert_eq!(later.value(), 4);
+    assert_eq!(later.clone().value(), 4);
+
+    let doubled = Eval::now(21).map(|x| x * 2);
+    assert_eq!(doubled.value(), 42);
+    assert_eq!(Eval::from(lazy).flat_map(|x| Eval::later(move || x + 1)).value(), 43);
+    assert_eq!(Trampoline::from(Eval::always(|| 1)).map(|x| x + 1).run(), 2);
+}
+
+#[test]
+// Trampoline runs deep recursion
+fn trampoline() {
+    fn is_even(n: u64) -> Trampoline<'static, bool> {
+        if n == 0 { Trampoline::done(true) } else { Trampoline::defer(move || is_odd(n - 1)) }
+    }
+    fn is_odd(n: u64) -> Trampoline<'static, bool> {
+        if n == 0 { Trampoline::done(false) } else { Trampoline::defer(move || is_even(n - 1)) }
+    }
+    assert!(is_even(1_000_000).run());
+    assert!(is_odd(999_999).run());
+
+    fn fib(n: u64) -> Trampoline<'static, u64> {
+        if n < 2 {
+            Trampoline::done(n)
+        } else {
+            Trampoline::defer(move || fib(n - 1)).flat_map(move |a| fib(n - 2).map(move |b| a + b))
+        }
+    }
+    assert_eq!(fib(20).run(), 6765);
+}
+
+#[test]
+// foldr_lazy short-circuits and is stack-safe
+fn foldr_lazy() {
+    use pa::basic_fn::fun::foldr_lazy;
+
+    let first_big = foldr_lazy(None, |x, rest| if x > 10 { Trampoline::done(Some(x)) } else { rest }, 1..);
+    assert_eq!(first_big, Some(11));
+
+    let list = foldr_lazy(Vec::new(), |x, rest: Trampoline<Vec<u32>>| rest.map(move |mut v| { v.push(x); v }),
+                          "abc".chars().map(|c| c as u32));
+    assert_eq!(list, vec![99, 98, 97]);
+
+    assert_eq!(foldr_lazy(0u64, |x, rest| rest.map(move |s| s + x), 1..=1_000_000u64), 500_000_500_000);
+
+    // borrowed sources and results
+    let words: Vec<String> = ["Houraisan", "Kaguya"].iter().map(|w| w.to_string()).collect();
+    let initials = foldr_lazy(String::new(), |w: &String, rest| rest.map(move |s| s + &w[..1]), words.iter());
+    assert_eq!(initials, "KH");
+    let longest = foldr_lazy(None, |w: &str, rest| rest.map(move |r: Option<&str>| match r {
+        Some(l) if l.len() >= w.len() => Some(l),
+        _ => Some(w),
+    }), words.iter().map(|w| w.as_str()));
+    assert_eq!(longest, Some("Houraisan"));
+}
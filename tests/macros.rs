@@ -0,0 +1,24 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate trybuild;
+
+#[test]
+// macros resolve through $crate and don't shadow std macros
+fn macro_hygiene() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
+
+#[test]
+#[cfg(feature = "std")]
+// #[memoize] rejects arguments which cannot be keys of its static cache
+fn memoize_args() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/memoize/*.rs");
+}
@@ -0,0 +1,123 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "std")]
+
+extern crate pa;
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use pa::memo::*;
+
+#[test]
+// memoize calls function once for every argument
+fn memoize_fn() {
+    let calls = Cell::new(0);
+    let square = memoize(|x: i32| {
+        calls.set(calls.get() + 1);
+        x * x
+    });
+    assert_eq!(square(3), 9);
+    assert_eq!(square(3), 9);
+    assert_eq!(square(4), 16);
+    assert_eq!(calls.get(), 2);
+
+    let calls = Cell::new(0);
+    let len = memoize_with(|s: &String| s.to_lowercase(), |s: String| {
+        calls.set(calls.get() + 1);
+        s.len()
+    });
+    assert_eq!(len("Abc".to_string()), 3);
+    assert_eq!(len("aBC".to_string()), 3);
+    assert_eq!(calls.get(), 1);
+
+    let calls = Cell::new(0);
+    let init = once(|| {
+        calls.set(calls.get() + 1);
+        vec![1, 2]
+    });
+    assert_eq!(init(), vec![1, 2]);
+    assert_eq!(init(), vec![1, 2]);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+// bounded caches
+fn lru() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, "a");
+    cache.insert(2, "b");
+    assert_eq!(cache.get(&1), Some(&"a"));
+    cache.insert(3, "c");
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some(&"a"));
+    assert_eq!(cache.remove(&3), Some("c"));
+
+    let calls = Cell::new(0);
+    let double = memoize_lru(1, |x: i32| {
+        calls.set(calls.get() + 1);
+        x * 2
+    });
+    assert_eq!((double(1), double(1), double(2), double(1)), (2, 2, 4, 2));
+    assert_eq!(calls.get(), 3);
+
+    let calls = Cell::new(0);
+    let double = memoize_ttl(10, Duration::from_millis(20), |x: i32| {
+        calls.set(calls.get() + 1);
+        x * 2
+    });
+    assert_eq!((double(1), double(1)), (2, 2));
+    thread::sleep(Duration::from_millis(30));
+    assert_eq!(double(1), 2);
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+// memoize_sync is shared by threads
+fn memoize_sync_fn() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let square = Arc::new(memoize_sync(move |x: u64| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        x * x
+    }));
+    assert_eq!(square(7), 49);
+    let handles: Vec<_> = (0..4).map(|_| {
+        let square = square.clone();
+        thread::spawn(move || square(7))
+    }).collect();
+    for h in handles {
+        assert_eq!(h.join().unwrap(), 49);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[memoize]
+fn fib(n: u64) -> u64 {
+    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+}
+
+#[memoize(sync)]
+fn paths(w: usize, h: usize) -> u64 {
+    if w == 0 || h == 0 {
+        return 1;
+    }
+    paths(w - 1, h) + paths(w, h - 1)
+}
+
+#[test]
+// #[memoize] caches recursive calls
+fn memoize_attr() {
+    assert_eq!(fib(90), 2880067194370816120);
+    assert_eq!(paths(16, 16), 601080390);
+    assert_eq!(thread::spawn(|| paths(17, 16)).join().unwrap(), 1166803110);
+}
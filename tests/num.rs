@@ -0,0 +1,83 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate pa;
+#[macro_use]
+extern crate proptest;
+
+use pa::num::*;
+
+#[test]
+// Signed and Fractional of integers and floats
+fn signed_and_fractional() {
+    assert_eq!(abs(-3_i8), 3);
+    assert_eq!(abs(-2.5_f32), 2.5);
+    assert_eq!(signum(-7_i64), -1);
+    assert_eq!(signum(0.0_f64), 0.0);
+    assert_eq!(signum(0.1_f64), 1.0);
+    assert_eq!(recip(8.0_f32), 0.125);
+    assert_eq!(i32::zero() + i32::one(), 1);
+}
+
+#[test]
+// even and odd of negative and unsigned numbers
+fn even_and_odd() {
+    assert!(even(0_u8));
+    assert!(odd(-1_i32));
+    assert!(even(-2_i64));
+    assert!(odd(i128::MIN + 1));
+    assert!(!odd(usize::MAX - 1));
+}
+
+#[test]
+// quot_rem truncates, div_mod floors
+fn integral_division() {
+    assert_eq!((-7).quot_rem(2), (-3, -1));
+    assert_eq!((-7).div_mod(2), (-4, 1));
+    assert_eq!(7.div_mod(-2), (-4, -1));
+    assert_eq!(6.div_mod(-3), (-2, 0));
+    assert_eq!(7_u32.div_mod(2), (3, 1));
+}
+
+#[test]
+// gcd, lcm and pow
+fn gcd_lcm_pow() {
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(gcd(-4, 0), 4);
+    assert_eq!(gcd(12_u64, 18), 6);
+    assert_eq!(gcd(i32::MIN, -1), 1);
+    assert_eq!(gcd(i64::MIN, 6), 2);
+    assert_eq!(lcm(-4, 6), 12);
+    assert_eq!(lcm(0, 6), 0);
+    assert_eq!(pow(2_u8, 7), 128);
+    assert_eq!(pow(-3, 3_u8), -27);
+    assert_eq!(pow(1.5, 0), 1.0);
+    assert_eq!(pow(2_i64, 62), 1 << 62);
+}
+
+#[test]
+#[should_panic(expected = "negative exponent")]
+// pow refuses negative exponents
+fn pow_negative() {
+    pow(2, -1);
+}
+
+proptest! {
+    #[test]
+    // gcd divides both and gcd * lcm == |x * y|
+    fn gcd_lcm_laws(x in -1000i64..1000, y in -1000i64..1000) {
+        let g = gcd(x, y);
+        prop_assert!(g >= 0);
+        if g != 0 {
+            prop_assert_eq!(x % g, 0);
+            prop_assert_eq!(y % g, 0);
+        }
+        prop_assert_eq!(g * lcm(x, y), abs(x * y));
+        let (q, r) = x.div_mod(if y == 0 { 1 } else { y });
+        prop_assert_eq!(q * (if y == 0 { 1 } else { y }) + r, x);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+use pa::optics::Lenses;
+
+#[derive(Debug, Clone, PartialEq, Lenses)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Lenses)]
+struct Shape {
+    name: String,
+    center: Point,
+    points: Vec<Point>,
+    r#type: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Lenses)]
+struct Pair<A, B>(A, B);
+
+struct NoClone;
+
+#[derive(Lenses)]
+#[allow(dead_code)]
+struct Holder {
+    inner: NoClone,
+    id: u32,
+}
+
+fn shape() -> Shape {
+    Shape {
+        name: "tri".to_string(),
+        center: Point { x: 0, y: 0 },
+        points: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }, Point { x: 5, y: 6 }],
+        r#type: Some(3),
+    }
+}
+
+#[test]
+// lens, view, set and over
+fn lens() {
+    use pa::optics::*;
+
+    let first = lens(|p: &(i32, &str)| p.0, |p: (i32, &str), a| (a, p.1));
+    assert_eq!(view(&first, &(1, "a")), 1);
+    assert_eq!(set(&first, 2, (1, "a")), (2, "a"));
+    assert_eq!(over(&first, |x| x * 10, (3, "a")), (30, "a"));
+    assert_eq!(first.put((1, "a"), 5), (5, "a"));
+    assert_eq!(preview(&first, &(1, "a")), Some(1));
+    assert_eq!(to_vec_of(&first, &(1, "a")), [1]);
+}
+
+#[test]
+// #[derive(Lenses)]
+fn derive_lenses() {
+    use pa::optics::*;
+
+    let p = Point { x: 1, y: 2 };
+    assert_eq!(view(&Point::lens_x(), &p), 1);
+    assert_eq!(set(&Point::lens_y(), 5, p.clone()), Point { x: 1, y: 5 });
+    assert_eq!(over(&Point::lens_x(), |x| x - 1, p), Point { x: 0, y: 2 });
+
+    assert_eq!(view(&Shape::lens_name(), &shape()), "tri");
+    assert_eq!(view(&Shape::lens_type(), &shape()), Some(3));
+
+    let pair = Pair(1, "a");
+    assert_eq!(view(&Pair::lens_1(), &pair), "a");
+    assert_eq!(set(&Pair::lens_0(), 2, pair), Pair(2, "a"));
+
+    let h = Holder { inner: NoClone, id: 1 };
+    assert_eq!(view(&Holder::lens_id(), &h), 1);
+}
+
+#[test]
+// compose lens by then and lens_path!
+fn compose() {
+    use pa::optics::*;
+
+    let center_x = Shape::lens_center().then(Point::lens_x());
+    assert_eq!(view(&center_x, &shape()), 0);
+    assert_eq!(view(&center_x, &set(&center_x, 9, shape())), 9);
+    assert_eq!(set(&center_x, 9, shape()).center, Point { x: 9, y: 0 });
+
+    let xs = lens_path!(Shape::lens_points(), each(), Point::lens_x());
+    assert_eq!(to_vec_of(&xs, &shape()), [1, 3, 5]);
+    let s = over(&xs, |x| x * 2, shape());
+    assert_eq!(to_vec_of(&xs, &s), [2, 6, 10]);
+    assert_eq!(view(&Shape::lens_name(), &s), "tri");
+    assert_eq!(preview(&xs, &s), Some(2));
+
+    let second_y = lens_path!(Shape::lens_points(), lens_index(1), Point::lens_y());
+    assert_eq!(preview(&second_y, &shape()), Some(4));
+    assert_eq!(to_vec_of(&xs, &set(&second_y, 0, shape())), [1, 3, 5]);
+    assert_eq!(set(&second_y, 0, shape()).points[1], Point { x: 3, y: 0 });
+
+    let tenth = Shape::lens_points().then(lens_index(10));
+    assert_eq!(preview(&tenth, &shape()), None);
+    assert_eq!(set(&tenth, Point { x: 0, y: 0 }, shape()), shape());
+}
+
+#[test]
+// prism and iso
+fn prism_iso() {
+    use pa::optics::*;
+
+    assert_eq!(preview(&some(), &Some(1)), Some(1));
+    assert_eq!(preview(&some::<i32>(), &None), None);
+    assert_eq!(over(&some(), |x| x + 1, Some(1)), Some(2));
+    assert_eq!(review(&some(), 1), Some(1));
+
+    let r: Result<i32, String> = Err("e".to_string());
+    assert_eq!(preview(&ok(), &r), None);
+    assert_eq!(preview(&err(), &r), Some("e".to_string()));
+    assert_eq!(set(&ok(), 1, r.clone()), r);
+    assert_eq!(review(&ok::<i32, String>(), 1), Ok(1));
+
+    let ty = Shape::lens_type().then(some());
+    assert_eq!(preview(&ty, &shape()), Some(3));
+    assert_eq!(set(&ty, 4, shape()).r#type, Some(4));
+
+    let even = prism(|x: &i32| if x % 2 == 0 { Some(x / 2) } else { None }, |x| x * 2);
+    assert_eq!(preview(&even, &4), Some(2));
+    assert_eq!(preview(&even, &3), None);
+    assert_eq!(over(&even, |x| x + 1, 4), 6);
+
+    let celsius = iso(|f: &f64| (f - 32.0) / 1.8, |c| c * 1.8 + 32.0);
+    assert_eq!(view(&celsius, &212.0), 100.0);
+    assert_eq!(review(&celsius, 0.0), 32.0);
+    assert_eq!(over(&celsius, |c| c + 10.0, 32.0), 50.0);
+
+    let chars = iso(|s: &String| s.chars().collect::<Vec<_>>(), |v: Vec<char>| v.into_iter().collect());
+    let both = chars.then(iso(|v: &Vec<char>| v.len(), |n| vec!['a'; n]));
+    assert_eq!(view(&both, &"abc".to_string()), 3);
+    assert_eq!(review(&both, 2), "aa");
+}
+
+#[test]
+// each, lens_index, at and folding
+fn index() {
+    use std::collections::{BTreeMap, VecDeque};
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+    use pa::optics::*;
+
+    assert_eq!(over(&each(), |x| x + 1, vec![1, 2, 3]), [2, 3, 4]);
+    assert_eq!(to_vec_of(&each(), &Some(1)), [1]);
+    let dq: VecDeque<i32> = (1..4).collect();
+    assert_eq!(to_vec_of(&each(), &over(&each(), |x| x * 2, dq.clone())), [2, 4, 6]);
+    assert_eq!(set(&lens_index(1), 0, dq), [1, 0, 3]);
+
+    assert_eq!(set(&lens_index(0), 9, vec![1, 2, 3]), [9, 2, 3]);
+    assert_eq!(over(&lens_index(2), |x| x + 1, vec![1, 2, 3]), [1, 2, 4]);
+    assert_eq!(set(&lens_index(3), 9, vec![1, 2, 3]), [1, 2, 3]);
+
+    #[cfg(feature = "std")]
+    {
+        let mut m = HashMap::new();
+        m.insert("a", 1);
+        assert_eq!(preview(&lens_index("a"), &m), Some(1));
+        assert_eq!(over(&lens_index("a"), |x| x + 1, m.clone())["a"], 2);
+        assert_eq!(set(&lens_index("b"), 1, m.clone()).len(), 1);
+        assert_eq!(view(&at("b"), &m), None);
+        assert_eq!(set(&at("b"), Some(2), m.clone())["b"], 2);
+        assert!(set(&at("a"), None, m.clone()).is_empty());
+        assert_eq!(to_vec_of(&each(), &over(&each(), |x| x * 3, m)), [3]);
+    }
+
+    let b: BTreeMap<i32, &str> = vec![(1, "a"), (2, "b")].into_iter().collect();
+    assert_eq!(to_vec_of(&each(), &b), ["a", "b"]);
+    assert_eq!(view(&at(2), &b), Some("b"));
+    assert_eq!(set(&at(1), None, b.clone()).len(), 1);
+    assert_eq!(set(&lens_index(1), "z", b)[&1], "z");
+
+    let digits = folding(|n: &u32| n.to_string().chars().map(|c| c.to_digit(10).unwrap()).collect());
+    assert_eq!(to_vec_of(&digits, &123), [1, 2, 3]);
+    assert_eq!(preview(&digits, &45), Some(4));
+    let points = Shape::lens_points().then(each()).then(folding(|p: &Point| vec![p.x, p.y]));
+    assert_eq!(to_vec_of(&points, &shape()), [1, 2, 3, 4, 5, 6]);
+}
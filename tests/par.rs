@@ -0,0 +1,69 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "parallel")]
+
+#[macro_use]
+extern crate pa;
+extern crate rayon;
+
+use rayon::iter::ParallelIterator;
+
+use pa::basic_fn::par::*;
+
+#[test]
+// par_map and par_filter keep the order of input
+fn par_map_filter() {
+    let v: Vec<u64> = (0..10_000).collect();
+    let doubled: Vec<_> = par_map(|x| x * 2, v.clone()).collect();
+    assert_eq!(doubled, v.iter().map(|x| x * 2).collect::<Vec<_>>());
+    assert_eq!(par_map_vec(|x: &u64| x + 1, &v)[..3], [1, 2, 3]);
+
+    let evens: Vec<_> = par_filter(|x| x % 2 == 0, 0..10).collect();
+    assert_eq!(evens, [0, 2, 4, 6, 8]);
+
+    let square = par_map!(|x: u64| x * x);
+    assert_eq!(square(vec![1, 2, 3]).collect::<Vec<_>>(), [1, 4, 9]);
+    let odd = par_filter!(|x: &u64| x % 2 == 1);
+    assert_eq!(odd(v).count(), 5_000);
+}
+
+#[test]
+// par_fold combines folded chunks in order
+fn par_fold_fn() {
+    let words: Vec<String> = (0..1_000).map(|x| x.to_string()).collect();
+    let joined: String = par_fold(|acc: String, w: &String| acc + w, &words);
+    assert_eq!(joined, words.concat());
+
+    let lengths = par_fold!(|mut acc: Vec<usize>, w: &String| { acc.push(w.len()); acc });
+    assert_eq!(lengths(&words).len(), 1_000);
+
+    let total = par_fold_with(0u64, |acc, x| acc + x, |a, b| a + b, 1..=1_000u64);
+    assert_eq!(total, 500_500);
+}
+
+#[test]
+// par_sorted, par_sorted_by and par_sum
+fn par_sorted_sum() {
+    let v: Vec<i64> = (0..1_000).map(|x| (x * 7919) % 1_000).collect();
+    let sorted: Vec<_> = par_sorted(v.clone()).collect();
+    assert_eq!(sorted, (0..1_000).collect::<Vec<_>>());
+    let desc: Vec<_> = par_sorted_by(|a, b| b.cmp(a), v.clone()).collect();
+    assert_eq!(desc[..3], [999, 998, 997]);
+    assert_eq!(par_sum(v), 499_500);
+    assert_eq!(par_sum(1..=100), 5050);
+}
+
+#[test]
+// par_pipe! runs stages prefixed by par in parallel
+fn par_pipe() {
+    let f = par_pipe!(|v: Vec<i32>| v, par |x: i32| x + 1, par |x: i32| x * 2, |v: Vec<i32>| v.into_iter().sum::<i32>());
+    assert_eq!(f(vec![1, 2, 3]), 18);
+
+    let g = par_pipe!(par |s: &str| s.len(), par_sum);
+    assert_eq!(g(vec!["Houraisan", "Kaguya"]), 15);
+}
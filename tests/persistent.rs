@@ -0,0 +1,170 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate pa;
+
+use pa::persistent::*;
+
+/// Deterministic pseudo random numbers for comparing with std collections
+fn lcg(seed: u64) -> impl Iterator<Item=u64> {
+    let mut state = seed;
+    std::iter::repeat_with(move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 33
+    })
+}
+
+#[test]
+// List
+fn list() {
+    let empty: List<i32> = List::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.head(), None);
+    assert!(empty.tail().is_none());
+
+    let l1 = empty.cons(3).cons(2);
+    let l2 = l1.cons(1);
+    assert_eq!(l1.len(), 2);
+    assert_eq!(l2.len(), 3);
+    assert_eq!(l2.head(), Some(&1));
+    assert_eq!(l2.tail(), Some(l1.clone()));
+    assert_eq!(l2.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(l1.iter().cloned().collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(l2.reverse(), (1..4).rev().collect());
+    assert_eq!((1..4).collect::<List<_>>(), l2);
+    assert_eq!(format!("{:?}", l2), "[1, 2, 3]");
+
+    // plug into basic_fn
+    use pa::basic_fn::fun::{map, sum};
+    assert_eq!(sum(map(|x| x * 2, l2.iter())), 12);
+
+    // long list can be dropped
+    let long: List<usize> = (0..1_000_000).collect();
+    assert_eq!(long.len(), 1_000_000);
+}
+
+#[test]
+// Vector
+fn vector() {
+    let empty: Vector<i32> = Vector::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.get(0), None);
+    assert!(empty.pop_back().is_none());
+
+    let v1: Vector<usize> = (0..2000).collect();
+    let v2 = v1.set(1000, 0).unwrap();
+    let v3 = v2.push_back(2000);
+    assert_eq!(v1.len(), 2000);
+    assert_eq!(v1.get(1000), Some(&1000));
+    assert_eq!(v2.get(1000), Some(&0));
+    assert_eq!(v3.len(), 2001);
+    assert_eq!(v3.last(), Some(&2000));
+    assert_eq!(v1.first(), Some(&0));
+    assert_eq!(v1.get(2000), None);
+    assert!(v1.set(2000, 0).is_none());
+    assert_eq!(v1.iter().cloned().collect::<Vec<_>>(), (0..2000).collect::<Vec<_>>());
+    assert_eq!(v1.iter().rev().take(2).cloned().collect::<Vec<_>>(), vec![1999, 1998]);
+    assert_eq!(v1.iter().len(), 2000);
+
+    // pop every element
+    let mut v = v3.clone();
+    for i in (0..2001).rev() {
+        assert_eq!(v.last(), if i == 1000 { Some(&0) } else { Some(&i) });
+        v = v.pop_back().unwrap();
+        assert_eq!(v.len(), i);
+    }
+    assert!(v.is_empty());
+    assert_eq!(v3.len(), 2001);
+
+    // push again after shrinking
+    let v4 = (0..40).fold(v1.pop_back().unwrap(), |acc, x| acc.push_back(x));
+    assert_eq!(v4.len(), 2039);
+    assert_eq!(v4.get(2038), Some(&39));
+    assert_eq!(v4, v1.iter().cloned().take(1999).chain(0..40).collect());
+}
+
+#[test]
+#[cfg(feature = "std")]
+// HashMap
+fn hash_map() {
+    let m1: HashMap<&str, i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    let m2 = m1.insert("c", 3);
+    let m3 = m2.insert("a", 10).remove("b");
+    assert_eq!(m1.len(), 2);
+    assert_eq!(m2.len(), 3);
+    assert_eq!(m3.len(), 2);
+    assert_eq!(m1.get("a"), Some(&1));
+    assert_eq!(m3.get("a"), Some(&10));
+    assert_eq!(m1.get("c"), None);
+    assert!(m2.contains_key("b"));
+    assert!(!m3.contains_key("b"));
+    assert_eq!(m3.remove("z"), m3);
+    let mut keys = m2.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+    assert_eq!(m2.values().sum::<i32>(), 6);
+
+    // compare with std
+    let mut std_map = std::collections::HashMap::new();
+    let mut map = HashMap::new();
+    for (i, x) in lcg(7).take(5000).enumerate() {
+        let k = x % 1000;
+        if i % 3 == 0 {
+            std_map.remove(&k);
+            map = map.remove(&k);
+        } else {
+            std_map.insert(k, i);
+            map = map.insert(k, i);
+        }
+        assert_eq!(map.len(), std_map.len());
+    }
+    assert_eq!(map.iter().count(), std_map.len());
+    for (k, v) in &std_map {
+        assert_eq!(map.get(k), Some(v));
+    }
+    assert_eq!(map, std_map.into_iter().collect());
+}
+
+#[test]
+// RedBlackMap
+fn red_black_map() {
+    let m1: RedBlackMap<i32, char> = vec![(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    let m2 = m1.insert(0, 'z').remove(&2);
+    assert_eq!(m1.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    assert_eq!(m2.keys().cloned().collect::<Vec<_>>(), vec![0, 1, 3]);
+    assert_eq!(m1.first(), Some((&1, &'a')));
+    assert_eq!(m2.last(), Some((&3, &'c')));
+    assert_eq!(m2.get(&2), None);
+    assert_eq!(m1.get(&2), Some(&'b'));
+    assert_eq!(m1.insert(1, 'x').get(&1), Some(&'x'));
+    assert_eq!(m1.insert(1, 'x').len(), 3);
+    assert_eq!(format!("{:?}", m1), "{1: 'a', 2: 'b', 3: 'c'}");
+
+    // compare with std
+    let mut std_map = std::collections::BTreeMap::new();
+    let mut map = RedBlackMap::new();
+    let mut versions = Vec::new();
+    for (i, x) in lcg(42).take(5000).enumerate() {
+        let k = x % 500;
+        if i % 3 == 0 {
+            std_map.remove(&k);
+            map = map.remove(&k);
+        } else {
+            std_map.insert(k, i);
+            map = map.insert(k, i);
+        }
+        assert_eq!(map.len(), std_map.len());
+        if i % 1000 == 0 {
+            versions.push((map.clone(), std_map.clone()));
+        }
+    }
+    assert!(map.iter().eq(std_map.iter()));
+    // old versions are untouched
+    for (map, std_map) in versions {
+        assert!(map.iter().eq(std_map.iter()));
+    }
+}
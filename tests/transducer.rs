@@ -0,0 +1,100 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+#[test]
+// transduce with compose! and pipe!
+fn transduce() {
+    use pa::transducer::*;
+
+    let xf = compose!(map(|x: i32| x * 2), filter(|x: &i32| x % 3 == 0));
+    assert_eq!(transduce(xf, |acc, x| acc + x, 0, 1..10), 6 + 12 + 18);
+
+    // pipe! is reversed, the last one sees the input first
+    let xf = pipe!(map(|x: i32| x.to_string()), filter_not(|x: &i32| x % 2 == 0));
+    assert_eq!(transduce(xf, |acc: String, x: String| acc + &x, String::new(), 1..10), "13579");
+
+    let xf = compose!(skip(2), map(|x: i32| x + 1));
+    assert_eq!(transduce(xf, |acc, x| acc * x, 1, vec![1, 2, 3, 4]), 20);
+}
+
+#[test]
+// take finishes early, infinite source is fine
+fn early_termination() {
+    use std::cell::Cell;
+    use pa::transducer::*;
+
+    let pulled = Cell::new(0);
+    let source = (1..).inspect(|_| pulled.set(pulled.get() + 1));
+    let xf = compose!(map(|x: i32| x * 2), filter(|x: &i32| x % 3 == 0), take(2));
+    assert_eq!(into(xf, Vec::new(), source), [6, 12]);
+    assert_eq!(pulled.get(), 6);
+
+    assert_eq!(into(take(0), Vec::new(), 1..), Vec::<i32>::new());
+    assert_eq!(into(compose!(take(5), skip(3)), Vec::new(), 1..), [4, 5]);
+}
+
+#[test]
+// into std and persistent collections
+fn into() {
+    use std::collections::BTreeSet;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+    use pa::persistent::{List, Vector, RedBlackMap};
+    use pa::transducer::*;
+
+    let v: Vector<i32> = into(map(|x: i32| x * x), Vector::new(), 1..4);
+    assert_eq!(v.iter().cloned().collect::<Vec<_>>(), [1, 4, 9]);
+    let l: List<i32> = into(take(3), List::new(), 1..);
+    assert_eq!(l.iter().cloned().collect::<Vec<_>>(), [3, 2, 1]);
+    let m = into(map(|x: i32| (x, x * 10)), RedBlackMap::new(), vec![2, 1]);
+    assert_eq!(m.get(&1), Some(&10));
+
+    #[cfg(feature = "std")]
+    {
+        let h = into(map(|x: i32| (x % 2, x)), HashMap::new(), 1..5);
+        assert_eq!(h[&0], 4);
+    }
+    let s = into(map(|x: i32| x % 3), BTreeSet::new(), 1..10);
+    assert_eq!(s.into_iter().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(into(filter(|c: &char| c.is_uppercase()), String::new(), "Houraisan Kaguya".chars()), "HK");
+}
+
+#[test]
+// lazy eduction
+fn eduction() {
+    use pa::transducer::*;
+
+    let mut it = eduction(compose!(filter(|x: &i32| x % 2 == 1), map(|x: i32| x * 10)), 1..);
+    assert_eq!(it.next(), Some(10));
+    assert_eq!(it.next(), Some(30));
+    assert_eq!(it.take(2).collect::<Vec<_>>(), [50, 70]);
+
+    let it = eduction(compose!(skip(1), take(2)), vec!["a", "b", "c", "d"]);
+    assert_eq!(it.collect::<Vec<_>>(), ["b", "c"]);
+    assert_eq!(eduction(take(3), Vec::<i32>::new()).next(), None);
+}
+
+#[test]
+// push-based sink
+fn sink() {
+    use pa::transducer::*;
+
+    let mut out = Vec::new();
+    {
+        let mut s = sink(compose!(filter(|x: &i32| *x > 0), take(2)), |x| out.push(x));
+        assert!(s.push(-1));
+        assert!(s.push(1));
+        assert!(!s.is_done());
+        assert!(s.push(2));
+        assert!(s.is_done());
+        assert!(!s.push(3));
+    }
+    assert_eq!(out, [1, 2]);
+}
@@ -0,0 +1,313 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+use pa::typeclass::*;
+
+#[derive(Debug, PartialEq)]
+struct Id<T>(T);
+
+impl<T> Kind for Id<T> {
+    type Item = T;
+    type Of<U> = Id<U>;
+}
+
+impl<T> Functor for Id<T> {
+    fn fmap<U>(self, mut f: impl FnMut(T) -> U) -> Id<U> {
+        Id(f(self.0))
+    }
+}
+
+impl<T> Pure for Id<T> {
+    fn pure(x: T) -> Self {
+        Id(x)
+    }
+}
+
+impl<T> Applicative for Id<T> {
+    fn pure_of<U>(x: U) -> Id<U> {
+        Id(x)
+    }
+
+    fn ap<U, F: FnMut(T) -> U>(self, fs: Id<F>) -> Id<U> {
+        let Id(mut f) = fs;
+        Id(f(self.0))
+    }
+
+    fn lift_a2<B: Clone, U>(self, fb: Id<B>, mut f: impl FnMut(T, B) -> U) -> Id<U> {
+        Id(f(self.0, fb.0))
+    }
+}
+
+impl<T> Monad for Id<T> {
+    fn bind<U>(self, mut f: impl FnMut(T) -> Id<U>) -> Id<U> {
+        f(self.0)
+    }
+}
+
+#[test]
+// Functor of user type
+fn functor() {
+    assert_eq!(Id(1).fmap(|x| x + 1), Id(2));
+    assert_eq!(fmap(|x: i32| x.to_string(), Id(1)), Id("1".to_string()));
+
+    // identity
+    assert_eq!(Id(3).fmap(pa::basic_fn::fun::id), Id(3));
+    // composition
+    let f = |x: i32| x * 2;
+    let g = |x: i32| x + 1;
+    assert_eq!(Id(3).fmap(compose!(f, g)), Id(3).fmap(g).fmap(f));
+}
+
+#[test]
+// Applicative of user type
+fn applicative() {
+    let x: Id<i32> = pure(1);
+    assert_eq!(x, Id(1));
+    assert_eq!(Id(1).ap(Id(|x| x + 1)), Id(2));
+    assert_eq!(ap(Id(|x: i32| x * 3), Id(2)), Id(6));
+
+    // homomorphism
+    let f = |x: i32| x - 1;
+    assert_eq!(Id::pure(5).ap(Id::pure(f)), Id::pure(f(5)));
+}
+
+#[test]
+// Monad of user type
+fn monad() {
+    assert_eq!(Id(1).bind(|x| Id(x + 1)), Id(2));
+    assert_eq!(bind(|x: i32| Id(x * 2), Id(4)), Id(8));
+
+    let f = |x: i32| Id(x + 1);
+    let g = |x: i32| Id(x * 10);
+    // left identity
+    assert_eq!(Id::pure(1).bind(f), f(1));
+    // right identity
+    assert_eq!(Id(1).bind(Id::pure), Id(1));
+    // associativity
+    assert_eq!(Id(1).bind(f).bind(g), Id(1).bind(|x| f(x).bind(g)));
+}
+
+#[test]
+// Semigroup and Monoid
+fn semigroup_and_monoid() {
+    use std::cmp::Ordering;
+    assert_eq!(mappend("Houraisan ".to_string(), "Kaguya".to_string()), "Houraisan Kaguya");
+    assert_eq!(mappend(vec![1, 2], vec![3]), vec![1, 2, 3]);
+    assert_eq!(mappend(Some(vec![1]), None), Some(vec![1]));
+    assert_eq!(mappend(Some(vec![1]), Some(vec![2])), Some(vec![1, 2]));
+    assert_eq!(mappend(Ordering::Equal, Ordering::Less), Ordering::Less);
+    assert_eq!(mappend(Ordering::Greater, Ordering::Less), Ordering::Greater);
+    assert_eq!(mappend(("a".to_string(), vec![1]), ("b".to_string(), vec![2])), ("ab".to_string(), vec![1, 2]));
+
+    let empty: String = mempty();
+    assert_eq!(empty, "");
+    assert_eq!(mconcat(vec!["a", "b", "c"].into_iter().map(String::from)), "abc");
+    assert_eq!(mconcat((0..0).map(|_| vec![0])), Vec::<i32>::new());
+}
+
+#[test]
+// Functor laws of std instances
+fn functor_laws() {
+    use std::collections::VecDeque;
+    use pa::basic_fn::fun::id;
+    let f = |x: i32| x * 2;
+    let g = |x: i32| x + 1;
+
+    assert_eq!(Some(1).fmap(id), Some(1));
+    assert_eq!(Some(1).fmap(compose!(f, g)), Some(1).fmap(g).fmap(f));
+    assert_eq!(None.fmap(compose!(f, g)), None.fmap(g).fmap(f));
+
+    let ok: Result<i32, String> = Ok(1);
+    let err: Result<i32, String> = Err("e".to_string());
+    assert_eq!(ok.clone().fmap(id), ok.clone());
+    assert_eq!(ok.clone().fmap(compose!(f, g)), ok.fmap(g).fmap(f));
+    assert_eq!(err.clone().fmap(compose!(f, g)), err.fmap(g).fmap(f));
+
+    assert_eq!(vec![1, 2, 3].fmap(id), vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3].fmap(compose!(f, g)), vec![1, 2, 3].fmap(g).fmap(f));
+
+    let dq: VecDeque<i32> = (1..4).collect();
+    assert_eq!(dq.clone().fmap(id), dq.clone());
+    assert_eq!(dq.clone().fmap(compose!(f, g)), dq.fmap(g).fmap(f));
+
+    assert_eq!(Box::new(1).fmap(id), Box::new(1));
+    assert_eq!(Box::new(1).fmap(compose!(f, g)), Box::new(1).fmap(g).fmap(f));
+
+    assert_eq!(("log", 1).fmap(id), ("log", 1));
+    assert_eq!(("log", 1).fmap(compose!(f, g)), ("log", 1).fmap(g).fmap(f));
+}
+
+#[test]
+// Applicative of std instances
+fn applicative_instances() {
+    use std::collections::VecDeque;
+    use pa::basic_fn::fun::id;
+
+    // identity
+    assert_eq!(Some(1).ap(Some(id)), Some(1));
+    assert_eq!(vec![1, 2].ap(vec![id]), vec![1, 2]);
+    // homomorphism
+    let f = |x: i32| x + 1;
+    assert_eq!(Option::pure(1).ap(Option::pure(f)), Option::pure(f(1)));
+    assert_eq!(Vec::pure(1).ap(Vec::pure(f)), Vec::pure(f(1)));
+
+    assert_eq!(None.ap(Some(f)), None);
+    let err: Result<i32, &str> = Err("e1");
+    assert_eq!(err.ap(Err::<fn(i32) -> i32, _>("e2")), Err("e2"));
+    assert_eq!(Ok::<_, &str>(1).lift_a2(Ok(2), |x, y| x + y), Ok(3));
+
+    // every function on every element
+    let fs: Vec<Box<dyn Fn(i32) -> i32>> = vec![Box::new(|x| x + 1), Box::new(|x| x * 10)];
+    assert_eq!(vec![1, 2].ap(fs), vec![2, 3, 10, 20]);
+    assert_eq!(vec![1, 2].lift_a2(vec!['a', 'b'], |x, y| (x, y)), vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    let dq: VecDeque<i32> = (1..3).collect();
+    assert_eq!(dq.lift_a2(vec![10].into_iter().collect(), |x, y| x * y), vec![10, 20].into_iter().collect::<VecDeque<_>>());
+
+    assert_eq!(Box::new(2).lift_a2(Box::new(3), |x, y| x * y), Box::new(6));
+    assert_eq!(("a".to_string(), 1).lift_a2(("b".to_string(), 2), |x, y| x + y), ("ab".to_string(), 3));
+}
+
+#[test]
+// Monad laws of std instances
+fn monad_laws() {
+    use std::collections::VecDeque;
+
+    let f = |x: i32| if x > 0 { Some(x - 1) } else { None };
+    let g = |x: i32| Some(x * 2);
+    assert_eq!(Option::pure(1).bind(f), f(1));
+    assert_eq!(Some(1).bind(Option::pure), Some(1));
+    assert_eq!(Some(3).bind(f).bind(g), Some(3).bind(|x| f(x).bind(g)));
+    assert_eq!(Some(0).bind(f).bind(g), Some(0).bind(|x| f(x).bind(g)));
+
+    let f = |x: i32| if x > 0 { Ok(x - 1) } else { Err("negative") };
+    let g = |x: i32| Ok(x * 2);
+    assert_eq!(Result::pure(1).bind(f), f(1));
+    assert_eq!(Ok::<_, &str>(1).bind(Result::pure), Ok(1));
+    assert_eq!(Ok(0).bind(f).bind(g), Ok(0).bind(|x| f(x).bind(g)));
+
+    let f = |x: i32| vec![x, x + 10];
+    let g = |x: i32| vec![x * 2];
+    assert_eq!(Vec::pure(1).bind(f), f(1));
+    assert_eq!(vec![1, 2].bind(Vec::pure), vec![1, 2]);
+    assert_eq!(vec![1, 2].bind(f).bind(g), vec![1, 2].bind(|x| f(x).bind(g)));
+
+    let f = |x: i32| (0..x).collect::<VecDeque<_>>();
+    let dq: VecDeque<i32> = (1..4).collect();
+    assert_eq!(VecDeque::pure(2).bind(f), f(2));
+    assert_eq!(dq.clone().bind(VecDeque::pure), dq.clone());
+    assert_eq!(dq.clone().bind(f).bind(f), dq.bind(|x| f(x).bind(f)));
+
+    let f = |x: i32| Box::new(x + 1);
+    assert_eq!(Box::pure(1).bind(f), f(1));
+    assert_eq!(Box::new(1).bind(Box::pure), Box::new(1));
+
+    let f = |x: i32| (vec![x], x + 1);
+    let g = |x: i32| (vec![x * 10], x);
+    assert_eq!(<(Vec<i32>, i32)>::pure(1).bind(f), f(1));
+    assert_eq!((vec![0], 1).bind(<(Vec<i32>, i32)>::pure), (vec![0], 1));
+    assert_eq!((vec![0], 1).bind(f).bind(g), (vec![0], 1).bind(|x| f(x).bind(g)));
+}
+
+#[test]
+// Foldable of std instances
+fn foldable() {
+    use std::collections::VecDeque;
+    assert_eq!(vec![1, 2, 3].foldl(0, |acc, x| acc * 10 + x), 123);
+    assert_eq!(vec![1, 2, 3].foldr(0, |acc, x| acc * 10 + x), 321);
+    assert_eq!((1..4).collect::<VecDeque<_>>().foldr(0, |acc, x| acc * 10 + x), 321);
+    assert_eq!(Some(1).foldl(1, |acc, x| acc + x), 2);
+    assert_eq!(None.foldl(1, |acc, x: i32| acc + x), 1);
+    assert_eq!(Err::<i32, _>("e").length(), 0);
+    assert_eq!(Box::new(5).length(), 1);
+    assert_eq!(("log", 2).foldl(1, |acc, x| acc + x), 3);
+    assert_eq!(vec![1, 2, 3].length(), 3);
+    assert_eq!(fold_map(|x: i32| x.to_string(), vec![1, 2, 3]), "123");
+}
+
+#[test]
+// Traversable of std instances
+fn traversable() {
+    use std::collections::VecDeque;
+    let half = |x: i32| if x % 2 == 0 { Some(x / 2) } else { None };
+    assert_eq!(vec![2, 4, 6].traverse(half), Some(vec![1, 2, 3]));
+    assert_eq!(vec![2, 3, 6].traverse(half), None);
+    assert_eq!(Vec::new().traverse(half), Some(vec![]));
+    assert_eq!(traverse(half, (1..4).map(|x| x * 2).collect::<VecDeque<_>>()), Some((1..4).collect::<VecDeque<_>>()));
+
+    // same order as Haskell
+    assert_eq!(vec![1, 2].traverse(|x| vec![x, x + 10]), vec![vec![1, 2], vec![1, 12], vec![11, 2], vec![11, 12]]);
+
+    assert_eq!(vec![Ok(1), Ok(2)].sequence(), Ok::<_, &str>(vec![1, 2]));
+    assert_eq!(vec![Ok(1), Err("e1"), Err("e2")].sequence(), Err::<Vec<i32>, _>("e1"));
+    assert_eq!(sequence(Some(vec![1, 2])), vec![Some(1), Some(2)]);
+    assert_eq!(None::<Vec<i32>>.sequence(), vec![None]);
+    assert_eq!(Ok::<_, &str>(Some(1)).sequence(), Some(Ok(1)));
+    assert_eq!(Box::new(Some(1)).sequence(), Some(Box::new(1)));
+    assert_eq!(("log", Some(1)).sequence(), Some(("log", 1)));
+
+    // identity
+    assert_eq!(vec![1, 2, 3].traverse(Id), Id(vec![1, 2, 3]));
+}
+
+#[test]
+// do-notation over std monads
+fn mdo() {
+    let r = mdo! { x <- Some(1); y <- Some(2); pure(x + y) };
+    assert_eq!(r, Some(3));
+    let r: Option<i32> = mdo! { x <- Some(1); _y <- None::<i32>; pure(x) };
+    assert_eq!(r, None);
+
+    let parse = |s: &str| s.parse::<i32>().map_err(|e| e.to_string());
+    let r = mdo! { x <- parse("4"); let y = x * 2; z <- parse("1"); Ok(y + z) };
+    assert_eq!(r, Ok::<i32, String>(9));
+    let r: Result<i32, String> = mdo! { x <- parse("4"); Err::<(), _>("stop".to_string()); pure(x) };
+    assert_eq!(r, Err("stop".to_string()));
+
+    let ys = vec!['a', 'b'];
+    let r = mdo! { x <- vec![1, 2]; y <- ys.clone(); pure((x, y)) };
+    assert_eq!(r, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    let r = mdo! { (a, b) <- vec![(1, 2), (3, 4)]; vec![a, b]; };
+    assert_eq!(r, vec![1, 2, 3, 4]);
+}
+
+#[test]
+// do-notation over iterators and user monad
+fn mdo_iter() {
+    let r: Vec<_> = mdo! { iter x <- 1..; y <- 0..x; pure((x, y)) }.take(4).collect();
+    assert_eq!(r, vec![(1, 0), (2, 0), (2, 1), (3, 0)]);
+    let r: String = mdo! { iter c <- "ab".chars(); vec![c, c] }.collect();
+    assert_eq!(r, "aabb");
+
+    let r = mdo! { x <- Id(1); let y = x + 1; pure(y * 10) };
+    assert_eq!(r, Id(20));
+}
+
+#[test]
+// ap! and lift! over std applicatives
+fn ap_and_lift() {
+    let add = |x: i32, y: i32| x + y;
+    assert_eq!(lift!(add, Some(1), Some(2)), Some(3));
+    assert_eq!(lift!(add, Some(1), None), None);
+    assert_eq!(lift!(|x: i32| x * 2, Ok::<i32, ()>(2)), Ok(4));
+    let r: Result<i32, &str> = lift!(|x: i32, y: i32, z: i32| x * y + z, Ok(2), Err("y"), Err("z"));
+    assert_eq!(r, Err("y"));
+    assert_eq!(lift!(|a: i32, b: char, c: bool| (a, b, c), vec![1, 2], vec!['a'], vec![true, false]),
+               vec![(1, 'a', true), (1, 'a', false), (2, 'a', true), (2, 'a', false)]);
+    let add3 = lift!(|x: i32, y: i32, z: i32| x + y + z, _, Some(10), _);
+    assert_eq!(add3(Some(1), Some(100)), Some(111));
+
+    let curried = |x: i32| move |y: i32| x * y;
+    assert_eq!(ap!(Some(curried), Some(3), Some(4)), Some(12));
+    assert_eq!(ap!(vec![|x: i32| x + 1, |x: i32| x * 10], vec![1, 2]), vec![2, 3, 10, 20]);
+    let apply = ap!(Some(|x: i32| x - 1));
+    assert_eq!(apply(Some(1)), Some(0));
+    let f = pipe!(ap!(Some(|x: i32| x + 1)), |o: Option<i32>| o.unwrap_or(0));
+    assert_eq!(f(Some(1)), 2);
+}
@@ -0,0 +1,14 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+// concat! of iterators is named concat_vec!, concat! is the one of std
+fn main() {
+    let _ = concat!(0..1;1..2);
+}
@@ -0,0 +1,16 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+// Macros don't need the functions in scope, nor bring them into scope
+fn main() {
+    let inc = map!(|x: i32| x + 1);
+    let _ = inc(0..3);
+    let _ = map(|x: i32| x + 1, 0..3);
+}
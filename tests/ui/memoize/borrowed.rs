@@ -0,0 +1,34 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+extern crate pa;
+
+use std::borrow::Cow;
+
+use pa::memo::memoize;
+
+// keys of the static cache cannot borrow
+#[memoize]
+fn len(s: &str) -> usize {
+    s.len()
+}
+
+#[memoize]
+fn first(v: Vec<&'static str>) -> Option<String> {
+    v.first().map(|s| s.to_string())
+}
+
+#[memoize]
+fn owned(s: Cow<'static, str>) -> String {
+    s.into_owned()
+}
+
+fn main() {
+    len("Kaguya");
+    first(vec!["Houraisan"]);
+    owned(Cow::Borrowed("Mokou"));
+}
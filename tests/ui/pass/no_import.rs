@@ -0,0 +1,26 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+// Local items of the same names must not be picked up by the macros
+#[allow(dead_code)]
+fn map() {}
+#[allow(dead_code)]
+fn foldl() {}
+#[allow(dead_code)]
+fn either() {}
+
+fn main() {
+    let odd_tens = pipe!(filter!(|x: &i32| x % 2 == 1), map!(|x| x * 10));
+    assert_eq!(odd_tens(1..6).collect::<Vec<_>>(), vec![10, 30, 50]);
+    assert_eq!(foldl!(0, |acc, x| acc + x)(1..4), 6);
+    assert_eq!(take!(2)(zip!(0..)(5..10)).collect::<Vec<_>>(), vec![(0, 5), (1, 6)]);
+    assert_eq!(either!(|l: i32| l + 1, |r: i32| r - 1, pa::adt::Either::Left(1)), 2);
+    assert_eq!(concat_vec!(0..1;1..3), vec![0, 1, 2]);
+}
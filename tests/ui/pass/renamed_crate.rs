@@ -0,0 +1,35 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// `::pa` is not in scope, proc macros are told the path of the renamed crate
+extern crate pa as kaguya;
+
+use kaguya::curry;
+use kaguya::optics::{view, Lenses};
+
+#[curry(crate = kaguya)]
+fn add3(x: i32, y: i32, z: i32) -> i32 {
+    x + y + z
+}
+
+#[curry(sub_c, crate = kaguya)]
+fn sub(x: i32, y: i32) -> i32 {
+    x - y
+}
+
+#[derive(Clone, Lenses)]
+#[lenses(crate = kaguya)]
+struct Princess {
+    name: String,
+}
+
+fn main() {
+    assert_eq!(add3(1)(2)(3), 6);
+    assert_eq!(sub_c(3)(1), sub(3, 1));
+    let p = Princess { name: "Kaguya".to_string() };
+    assert_eq!(view(&Princess::lens_name(), &p), "Kaguya");
+}
@@ -0,0 +1,14 @@
+// Copyright 2018 KaguyaRs Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate pa;
+
+fn main() {
+    assert_eq!(concat!("Kaguya", 1), "Kaguya1");
+    assert_eq!(concat_vec!(0..2;vec![2].into_iter()), vec![0, 1, 2]);
+}